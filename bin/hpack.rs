@@ -0,0 +1,344 @@
+// A command-line tool for HPACK header blocks, e.g. copied from packet captures.
+//
+//   hpack decode    Decode header blocks, one hex encoded block per line
+//   hpack encode    Encode header lists of "name: value" lines, separated by empty lines
+//   hpack inspect   Print every representation of each header block, and its
+//                   effect on the header table
+//
+// All header blocks read by one invocation share a single encoding or decoding context.
+// Build it next to the library with `rustc -L . bin/hpack.rs -o hpack`.
+#![crate_id = "hpack_tool#0.1"]
+#![crate_type = "bin"]
+
+#![feature(macro_rules)]
+
+extern crate getopts;
+extern crate serialize;
+extern crate hpack;
+
+use std::io;
+use std::os;
+use std::str;
+use getopts::{optopt, optmulti, optflag, getopts, usage};
+use serialize::hex::{ToHex, FromHex};
+
+use hpack::{Encoder, Decoder, HeaderField};
+use hpack::disassembler::{disassemble, Parsed, IndexedHeaderField, IndexedNameLiteral, NewNameLiteral, SizeUpdate};
+
+// Macro rule to unwrap a result
+// If Err, the function using this macro will return with the error
+// Invoke it like `propagate_err!(result)`
+macro_rules! propagate_err(
+    ($inp:expr) => (
+        match $inp {
+            Ok(x)  => x,
+            Err(e) => return Err(e),
+        }
+    );
+)
+
+struct Options {
+    // The initial header table size, if not the default of 4096 octets
+    table_size: Option<uint>,
+    // Names of header fields the encoder sends as literals never indexed
+    sensitive: ~[~str],
+    // Binary header blocks instead of hex
+    raw: bool,
+}
+
+fn main() {
+    let args = os::args();
+
+    let opts = ~[
+        optopt("t", "table-size", "initial header table size, i.e. SETTINGS_HEADER_TABLE_SIZE (default 4096)", "OCTETS"),
+        optmulti("s", "sensitive", "encode header fields with this name as literals never indexed", "NAME"),
+        optflag("r", "raw", "read (decode, inspect) or write (encode) a single binary header block instead of hex lines"),
+        optflag("h", "help", "print this help"),
+    ];
+
+    let brief = format!("Usage: {} [options] decode|encode|inspect", args[0]);
+
+    let matches = match getopts(args.tail(), opts.as_slice()) {
+        Ok(matches) => matches,
+        Err(f) => return exit_with(f.to_err_msg()),
+    };
+
+    if matches.opt_present("help") || matches.free.len() != 1 {
+        println!("{}", usage(brief.as_slice(), opts.as_slice()));
+        if !matches.opt_present("help") {
+            os::set_exit_status(2);
+        }
+        return;
+    }
+
+    let table_size = match matches.opt_str("table-size") {
+        Some(size) => match from_str::<uint>(size.as_slice()) {
+            Some(size) => Some(size),
+            None       => return exit_with(format!("invalid table size: {}", size)),
+        },
+        None => None,
+    };
+
+    let options = Options {
+        table_size: table_size,
+        sensitive: matches.opt_strs("sensitive"),
+        raw: matches.opt_present("raw"),
+    };
+
+    let result = match matches.free[0].as_slice() {
+        "decode"  => decode(&options),
+        "encode"  => encode(&options),
+        "inspect" => inspect(&options),
+        command   => Err(format!("unknown command: {}", command)),
+    };
+
+    match result {
+        Ok(()) => {},
+        Err(msg) => exit_with(msg),
+    }
+}
+
+fn exit_with(msg: ~str) {
+    let _ = io::stderr().write_line(format!("hpack: {}", msg).as_slice());
+    os::set_exit_status(1);
+}
+
+fn new_decoder(options: &Options) -> Decoder {
+    match options.table_size {
+        Some(size) => Decoder::with_max_table_size(size),
+        None       => Decoder::new(),
+    }
+}
+
+// A header field as "name: value". Octets that are not UTF-8 are replaced.
+fn show_field(name: &[u8], value: &[u8]) -> ~str {
+    format!("{}: {}", str::from_utf8_lossy(name).into_owned(), str::from_utf8_lossy(value).into_owned())
+}
+
+// Parse a header field from "name: value".
+// The name of a pseudo-header field starts with a colon, e.g. ":method: GET".
+fn parse_field(line: &str) -> Option<HeaderField> {
+    let start = if line.starts_with(":") { 1 } else { 0 };
+
+    match line.slice_from(start).find(':') {
+        Some(i) => {
+            let name = line.slice_to(start + i);
+            let value = line.slice_from(start + i + 1).trim_left();
+
+            Some(HeaderField::new(name.to_owned(), value.to_owned()))
+        },
+        None => None
+    }
+}
+
+// Call `f` with the number (from 1) and the octets of every header block on stdin.
+// Every non-empty line is a hex encoded header block, in which white space is ignored.
+// With --raw all of stdin is a single header block.
+fn each_block(raw: bool, f: |uint, &[u8]| -> Result<(), ~str>) -> Result<(), ~str> {
+    let mut stdin = io::stdin();
+
+    if raw {
+        return match stdin.read_to_end() {
+            Ok(block) => f(1, block.as_slice()),
+            Err(e)    => Err(format!("{}", e)),
+        };
+    }
+
+    let mut number = 0;
+
+    loop {
+        let line = match stdin.read_line() {
+            Ok(line) => line,
+            Err(ref e) if e.kind == io::EndOfFile => return Ok(()),
+            Err(e) => return Err(format!("{}", e)),
+        };
+
+        let hex: ~str = line.chars().filter(|c| !c.is_whitespace()).collect();
+        if hex.is_empty() {
+            continue;
+        }
+
+        number += 1;
+
+        match hex.as_slice().from_hex() {
+            Ok(block) => propagate_err!(f(number, block.as_slice())),
+            Err(e)    => return Err(format!("block {}: {}", number, e)),
+        }
+    }
+}
+
+fn decode(options: &Options) -> Result<(), ~str> {
+    let mut decoder = new_decoder(options);
+
+    each_block(options.raw, |number, block| {
+        let fields = match decoder.decode_slice(block) {
+            Ok(fields) => fields,
+            Err(e)     => return Err(format!("block {}: {} at offset {}", number, e, e.offset())),
+        };
+
+        for field in fields.iter() {
+            println!("{}", show_field(field.key.as_slice(), field.value.as_slice()));
+        }
+        println!("");
+
+        Ok(())
+    })
+}
+
+fn encode(options: &Options) -> Result<(), ~str> {
+    let mut encoder = match options.table_size {
+        Some(size) => Encoder::with_max_table_size(size),
+        None       => Encoder::new(),
+    };
+
+    for name in options.sensitive.iter() {
+        encoder.add_sensitive_name(name.clone());
+    }
+
+    let mut stdin = io::stdin();
+    let mut fields = ~[];
+    let mut number = 0;
+
+    loop {
+        let line = match stdin.read_line() {
+            Ok(line) => line,
+            Err(ref e) if e.kind == io::EndOfFile => break,
+            Err(e) => return Err(format!("{}", e)),
+        };
+        number += 1;
+
+        let line = line.trim_right();
+
+        // An empty line ends a header list
+        if line.is_empty() {
+            if !fields.is_empty() {
+                propagate_err!(write_block(&mut encoder, fields.as_slice(), options.raw));
+                fields.truncate(0);
+            }
+            continue;
+        }
+
+        match parse_field(line) {
+            Some(field) => fields.push(field),
+            None        => return Err(format!("line {}: expected \"name: value\"", number)),
+        }
+    }
+
+    if !fields.is_empty() {
+        propagate_err!(write_block(&mut encoder, fields.as_slice(), options.raw));
+    }
+
+    Ok(())
+}
+
+// Encode a header list in order, and write the header block
+fn write_block(encoder: &mut Encoder, fields: &[HeaderField], raw: bool) -> Result<(), ~str> {
+    let block = match encoder.encode_list(fields) {
+        Ok(block) => block,
+        Err(e)    => return Err(format!("{}", e)),
+    };
+
+    if raw {
+        match io::stdout().write(block.as_slice()) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(format!("{}", e)),
+        }
+    } else {
+        println!("{}", block.to_hex());
+        Ok(())
+    }
+}
+
+fn literal_kind(indexing: bool, never_indexed: bool) -> &'static str {
+    if never_indexed {
+        "literal never indexed"
+    } else if indexing {
+        "literal with indexing"
+    } else {
+        "literal without indexing"
+    }
+}
+
+fn huffman_flag(huffman: bool) -> &'static str {
+    if huffman { "huffman" } else { "plain" }
+}
+
+// The type of a representation, its index (or new maximum size)
+// and whether its strings are Huffman encoded, e.g. "huffman/plain"
+// for a Huffman encoded new name with a plain value
+fn describe(representation: &Parsed) -> ~str {
+    let (kind, index, huffman) = match *representation {
+        IndexedHeaderField(ref header) => {
+            ("indexed", format!("index {}", header.index), ~"")
+        },
+        IndexedNameLiteral(ref literal) => {
+            (literal_kind(literal.indexing, literal.never_indexed), format!("index {}", literal.index),
+             huffman_flag(literal.value_huffman).to_owned())
+        },
+        NewNameLiteral(ref literal) => {
+            (literal_kind(literal.indexing, literal.never_indexed), ~"new name",
+             format!("{}/{}", huffman_flag(literal.name_huffman), huffman_flag(literal.value_huffman)))
+        },
+        SizeUpdate(ref update) => {
+            ("size update", format!("max size {}", update.max_size), ~"")
+        },
+    };
+
+    format!("{:<25} {:<15} {:<14}", kind, index, huffman)
+}
+
+fn inspect(options: &Options) -> Result<(), ~str> {
+    let mut decoder = new_decoder(options);
+
+    each_block(options.raw, |number, block| {
+        println!("block {} ({} octets)", number, block.len());
+
+        // The representations before one that cannot be parsed are still shown
+        let (representations, error) = disassemble(block);
+
+        for representation in representations.iter() {
+            let max_size = decoder.header_table().max_size();
+
+            let resolved = match representation.resolve(block, &mut decoder) {
+                Ok(resolved) => resolved,
+                Err(e) => return Err(format!("block {}: {} at offset {}", number, e, e.offset())),
+            };
+
+            let field = match resolved.field {
+                Some(ref hf) => show_field(hf.key.as_slice(), hf.value.as_slice()),
+                None         => ~"",
+            };
+            println!("{:6u}  {} {}", representation.start, describe(&representation.representation), field);
+
+            let table = decoder.header_table();
+            let max_size_changed = table.max_size() != max_size;
+
+            if max_size_changed {
+                println!("        max size {} -> {}", max_size, table.max_size());
+            }
+            match resolved.inserted {
+                // The new entry has the first index after the static table
+                Some(ref hf) => println!("        + [62] {} ({} octets)", show_field(hf.key.as_slice(), hf.value.as_slice()), hf.size()),
+                None         => {},
+            }
+            for hf in resolved.evicted.iter() {
+                println!("        - {} ({} octets)", show_field(hf.key.as_slice(), hf.value.as_slice()), hf.size());
+            }
+            if max_size_changed || resolved.inserted.is_some() || !resolved.evicted.is_empty() {
+                println!("        table: {} entries, {}/{} octets", table.len(), table.size(), table.max_size());
+            }
+        }
+
+        match error {
+            Some(e) => return Err(format!("block {}: {} at offset {}", number, e, e.offset())),
+            None    => {},
+        }
+        match decoder.finish() {
+            Ok(()) => {},
+            Err(e) => return Err(format!("block {}: {} at offset {}", number, e, e.offset())),
+        }
+        println!("");
+
+        Ok(())
+    })
+}
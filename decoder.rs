@@ -1,35 +1,252 @@
-// Comments enclosed in quotes are citations from the HPACK draft:
-// http://tools.ietf.org/html/draft-ietf-httpbis-header-compression-07
-use std::str;
-
-use header_table::{HeaderTable, DEFAULT_HEADER_TABLE_SIZE};
-use header_set::HeaderSet;
-use reference_set::ReferenceSet;
-use integer_representation::decode_int;
-use header_field::HeaderField;
+// Comments enclosed in quotes are citations from RFC 7541:
+// http://tools.ietf.org/html/rfc7541
+use std::mem;
+use std::rc::Rc;
+
+use header_table::{HeaderTable, HeaderTableView, DEFAULT_HEADER_TABLE_SIZE};
+use integer::{IntegerDecoder, IntegerError, Truncated, Overflow, decode_int};
+use header_field::{HeaderField, HeaderFieldRef, MaybeOwnedBytes, Slice, Owned, SharedKey, SharedValue};
 use header_collection::HeaderCollection;
 use static_header_table::StaticHeaderTable;
-use huffman::huffman_decoder::HuffmanDecoder;
+use huffman::huffman_decoder::{HuffmanDecoder, EosSymbol, InvalidPadding};
+use indexing_policy::{Indexing, Incremental, WithoutIndexing, NeverIndexed};
+use snapshot::{DecoderState, SnapshotError, InvalidSnapshot, BlockInProgress, ContextPoisoned, check_entries};
+
+/// The reason a header block could not be decoded.
+/// Every variant but `Poisoned` carries the byte offset inside the header block
+/// of the representation, integer or string that failed to decode.
+#[deriving(Eq, Show, Clone)]
+pub enum DecoderError {
+    /// The index (first value) refers to neither the static nor the dynamic table.
+    InvalidIndex(uint, uint),
+    /// An integer does not fit into an `uint`.
+    IntegerOverflow(uint),
+    /// The header block ended in the middle of an integer.
+    TruncatedInteger(uint),
+    /// The header block ended before the end of a string literal.
+    TruncatedString(uint),
+    /// A Huffman-encoded string literal contains the EOS symbol.
+    InvalidHuffmanCode(uint),
+    /// A Huffman-encoded string literal is padded with more than 7 bits,
+    /// or with bits that are not the most significant bits of the EOS symbol.
+    InvalidHuffmanPadding(uint),
+    /// A dynamic table size update (first value) exceeds the allowed maximum.
+    TableSizeUpdateTooLarge(uint, uint),
+    /// A dynamic table size update follows a header field representation.
+    UnexpectedTableSizeUpdate(uint),
+    /// The header block does not start with the dynamic table size update
+    /// required after SETTINGS_HEADER_TABLE_SIZE has been lowered.
+    MissingTableSizeUpdate(uint),
+    /// The decoded header list exceeds the maximum header list size,
+    /// i.e. the SETTINGS_MAX_HEADER_LIST_SIZE we have advertised, from the
+    /// representation at the offset on. The rest of the header block is still
+    /// decoded and applied to the header table, but its header fields are dropped.
+    /// The header table is kept in sync with the peer, so unlike the other errors
+    /// this only calls for refusing the stream.
+    HeaderListTooLarge(uint),
+    /// An earlier header block failed to decode, and the error mode is `Poison`.
+    /// Nothing is decoded until `reset` is called.
+    Poisoned,
+}
+
+/// What the decoder does with its header table when a header block fails to decode.
+/// A header list that is too large does not count, as its header block is decoded completely.
+/// Either way the peer's encoding context has applied the whole header block, so the
+/// error must be treated as a connection error of type COMPRESSION_ERROR.
+#[deriving(Eq, Show, Clone)]
+pub enum ErrorMode {
+    /// Undo every change of the header table made by the failed header block (the default),
+    /// so a header block changes the header table either completely or not at all.
+    RollBack,
+    /// Refuse every later header block with `Poisoned`, until `reset` is called.
+    Poison,
+}
+
+impl DecoderError {
+    /// The byte offset inside the header block where the error occurred.
+    pub fn offset(&self) -> uint {
+        match *self {
+            InvalidIndex(_, offset)            => offset,
+            IntegerOverflow(offset)            => offset,
+            TruncatedInteger(offset)           => offset,
+            TruncatedString(offset)            => offset,
+            InvalidHuffmanCode(offset)         => offset,
+            InvalidHuffmanPadding(offset)      => offset,
+            TableSizeUpdateTooLarge(_, offset) => offset,
+            UnexpectedTableSizeUpdate(offset)  => offset,
+            MissingTableSizeUpdate(offset)     => offset,
+            HeaderListTooLarge(offset)         => offset,
+            Poisoned                           => 0,
+        }
+    }
+}
+
+// The kind of representation being decoded
+enum Kind {
+    IndexedField,                 // Indexed Header Field
+    LiteralIndexedName(Indexing), // Literal Header Field - Indexed Name
+    LiteralNewName(Indexing),     // Literal Header Field - New Name
+    SizeUpdate,                   // Dynamic Table Size Update
+}
+
+impl Kind {
+    // The number of bits of the first octet used for the index or size
+    fn prefix_size(&self) -> u8 {
+        match *self {
+            IndexedField                    => 7,
+            LiteralIndexedName(Incremental) => 6,
+            LiteralIndexedName(_)           => 4,
+            SizeUpdate                      => 5,
+            LiteralNewName(_)               => 8, // The whole first octet is 0
+        }
+    }
 
-// Macro rule to unwrap an option
-// If None, the function using this macro will return with None
-// Invoke it like `propagate_err!(option)`
-macro_rules! propagate_err(
-    ($inp:expr) => ( 
-        match $inp {
-            Some(x) => x,
-            None    => return None,
+    // Whether the header field is added to the header table
+    fn indexing(&self) -> bool {
+        match *self {
+            LiteralIndexedName(indexing) => indexing == Incremental,
+            LiteralNewName(indexing)     => indexing == Incremental,
+            _                            => false,
         }
-    );
-)
+    }
+
+    // Whether the header field is sensitive, i.e. it must be
+    // encoded as a literal never indexed by intermediaries as well
+    fn never_indexed(&self) -> bool {
+        match *self {
+            LiteralIndexedName(indexing) => indexing == NeverIndexed,
+            LiteralNewName(indexing)     => indexing == NeverIndexed,
+            _                            => false,
+        }
+    }
+}
+
+// What the decoder expects from the next octet of the header block
+enum Step {
+    FirstOctet,                       // The first octet of a representation
+    Prefix(IntegerDecoder),           // The rest of the index or size starting the representation
+    StringStart,                      // The first octet of a string literal
+    StringLength(bool, IntegerDecoder), // The rest of the length of a (Huffman encoded) string literal
+    StringOctets(bool, uint, ~[u8]),  // The octets of a (Huffman encoded) string literal of a given length
+}
+
+// A change of the header table by the current header block, so it can be undone
+enum Change {
+    Added,                // A header field was added
+    Evicted(Rc<HeaderField>), // The oldest header field was evicted
+    Resized(uint, bool),  // A size update replaced the maximum size and whether a size update was required
+}
+
+// The position of the decoder inside the current header block.
+// It survives between fragments of the same header block.
+struct BlockState {
+    offset:                uint, // Octets of the header block consumed so far
+    field_seen:            bool, // Whether a header field representation has been started
+    step:                  Step,
+    kind:                  Kind,
+    representation_offset: uint,
+    string_offset:         uint,
+    name:                  Option<~[u8]>,
+    list_size:             uint, // The size of the header fields decoded so far
+    list_too_large:        Option<uint>, // The offset of the first header field beyond the maximum list size
+    changes:               ~[Change],
+}
+
+impl BlockState {
+    fn new() -> BlockState {
+        BlockState {
+            offset:                0,
+            field_seen:            false,
+            step:                  FirstOctet,
+            kind:                  IndexedField,
+            representation_offset: 0,
+            string_offset:         0,
+            name:                  None,
+            list_size:             0,
+            list_too_large:        None,
+            changes:               ~[],
+        }
+    }
+}
+
+// A read position inside a complete header block
+// It is also used by the disassembler.
+pub struct Cursor<'a> {
+    buffer:   &'a [u8],
+    position: uint,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(buffer: &'a [u8]) -> Cursor<'a> {
+        Cursor {
+            buffer:   buffer,
+            position: 0,
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.position == self.buffer.len()
+    }
+
+    pub fn peek(&self) -> u8 {
+        self.buffer[self.position]
+    }
+
+    /*
+     * Reads an integer from the header block (and moves past it)
+     */
+    pub fn read_int(&mut self, n: u8) -> Result<uint, DecoderError> {
+        let offset = self.position;
+
+        match decode_int(self.buffer.slice_from(self.position), n) {
+            Ok((value, length)) => {
+                self.position += length;
+                Ok(value)
+            },
+            Err(Truncated) => Err(TruncatedInteger(offset)),
+            Err(Overflow)  => Err(IntegerOverflow(offset)),
+        }
+    }
+
+    /*
+     * Reads a string from the header block (and moves past it)
+     * Returns whether it is Huffman encoded and its octets (still encoded)
+     */
+    pub fn read_string(&mut self) -> Result<(bool, &'a [u8]), DecoderError> {
+        let offset = self.position;
+        let buffer = self.buffer;
+
+        if self.is_empty() {
+            return Err(TruncatedString(offset));
+        }
+
+        let huffman_encoded = self.peek() & 0x80 == 0x80; // 1XXX XXXX & 1000 0000 == 1000 0000
+        let string_length = propagate_err!(self.read_int(7));
+
+        if string_length > buffer.len() - self.position {
+            return Err(TruncatedString(offset));
+        }
+
+        let string = buffer.slice(self.position, self.position + string_length);
+        self.position += string_length;
+
+        Ok((huffman_encoded, string))
+    }
+}
 
 /// An implementation of an HPACK decoding context for HTTP/2.
 pub struct Decoder {
     priv header_table:        ~HeaderTable,
-    priv reference_set:       ~ReferenceSet,
-    priv header_set:          ~HeaderSet,
     priv static_header_table: ~StaticHeaderTable,
     priv huffman_decoder:     HuffmanDecoder,
+    priv state:               BlockState,
+    // The SETTINGS_HEADER_TABLE_SIZE we have advertised
+    priv protocol_max_table_size: uint,
+    priv size_update_required:    bool,
+    // The SETTINGS_MAX_HEADER_LIST_SIZE we have advertised, if any
+    priv max_header_list_size:    Option<uint>,
+    priv error_mode:              ErrorMode,
+    priv poisoned:                bool,
 }
 
 impl Decoder {
@@ -37,267 +254,598 @@ impl Decoder {
     pub fn new() -> Decoder {
         Decoder {
             header_table:        ~HeaderTable::new(DEFAULT_HEADER_TABLE_SIZE),
-            reference_set:       ~ReferenceSet::new(),
-            header_set:          ~HeaderSet::new(),
             static_header_table: ~StaticHeaderTable::new(),
             huffman_decoder:     HuffmanDecoder::new(),
+            state:               BlockState::new(),
+            protocol_max_table_size: DEFAULT_HEADER_TABLE_SIZE,
+            size_update_required:    false,
+            max_header_list_size:    None,
+            error_mode:              RollBack,
+            poisoned:                false,
         }
     }
 
-    /// Decode a headerblock into a set of header fields. Return `None` if a decoding error has occurred.
-    pub fn decode(&mut self, mut header_block: ~[u8]) -> Option<HeaderCollection> {
-        // TODO: Should we just empty the existing instead (memory leak) ?
-        self.header_set = ~HeaderSet::new();
-        self.reference_set.reset();
+    /// Create an empty decoding context with a header table size other than
+    /// the default of 4096 octets, which the peer is known to use already,
+    /// e.g. from SETTINGS_HEADER_TABLE_SIZE. It is also the upper bound for the size.
+    pub fn with_max_table_size(max_size: uint) -> Decoder {
+        let mut decoder = Decoder::new();
+        decoder.header_table.set_max_size(max_size);
+        decoder.protocol_max_table_size = max_size;
 
-        while header_block.len() > 0 {
-            let representation_type = header_block[0];
+        decoder
+    }
 
-            if representation_type >= 0x80 {                // 1XXX XXXX = Indexed Header Field
-                header_block = propagate_err!(self.decode_indexed_header(header_block));
+    /// Set the upper bound for the header table size the peer may choose,
+    /// i.e. the SETTINGS_HEADER_TABLE_SIZE we have advertised.
+    /// Call it once the peer has acknowledged the setting.
+    /// If the bound is lower than the current header table size,
+    /// the next header block must start with a Dynamic Table Size Update.
+    pub fn set_protocol_max_table_size(&mut self, max_size: uint) {
+        if max_size < self.header_table.get_max_size() {
+            self.size_update_required = true;
+        }
 
-            } else if representation_type == 0x00 ||        // 0000 0000 = Literal Header Field - New Name
-                      representation_type == 0x40 ||        // 0100 0000 = Literal Header Field - New Name
-                      representation_type == 0x10 {         // 0001 0000 = Literal Header Field never Indexed - New Name
-                header_block = propagate_err!(self.decode_string_literal(header_block));
+        self.protocol_max_table_size = max_size;
+    }
 
-            } else if representation_type & 0xC0 == 0x40 || // 01XX XXXX & 1100 0000 == 0100 0000
-                      representation_type & 0xF0 == 0x00 || // 0000 XXXX & 1111 0000 == 0000 0000
-                      representation_type & 0xF0 == 0x10 {  // 0001 XXXX & 1111 0000 == 0001 0000
-                header_block = propagate_err!(self.decode_indexed_literal(header_block));
+    /// Set the maximum size of a decoded header list, i.e. the SETTINGS_MAX_HEADER_LIST_SIZE
+    /// we have advertised. The size of a header list is the sum of the sizes of its header fields,
+    /// as given by `HeaderField::size`. By default the size is unlimited.
+    pub fn set_max_header_list_size(&mut self, max_size: uint) {
+        self.max_header_list_size = Some(max_size);
+    }
 
-            } else if representation_type & 0xE0 == 0x20 {  // 001X XXXX & 1110 0000 == 0010 0000
-                header_block = propagate_err!(self.decode_context_update(header_block));
-            } else {
-                return None;
-            }
-        }
+    /// Choose what happens to the header table when a header block fails to decode.
+    pub fn set_error_mode(&mut self, mode: ErrorMode) {
+        self.error_mode = mode;
+    }
 
-        // 3.2.2.  Reference Set Emission
-        //
-        // Emit all header fields in the reference set that are not already emitted
-        for (hf, emit) in self.reference_set.references.iter() {
-            match *emit {
-                false => {
-                    self.header_set.emit(hf.clone());
-                },
-                _ => {}
-            } 
+    /// Whether a header block failed to decode with the error mode `Poison`.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    /// Start over with an empty header table of the size we have advertised,
+    /// like the decoder of a new connection. The settings and the error mode are kept.
+    /// This ends the poisoned state.
+    pub fn reset(&mut self) {
+        self.header_table = ~HeaderTable::new(self.protocol_max_table_size);
+        self.state = BlockState::new();
+        self.size_update_required = false;
+        self.poisoned = false;
+    }
+
+    /// A read-only view of the header table, e.g. to inspect its entries and size.
+    pub fn header_table<'a>(&'a self) -> HeaderTableView<'a> {
+        self.header_table.view()
+    }
+
+    /// Take a snapshot of the decoding context, from which `restore` builds an identical one.
+    /// It cannot be taken in the middle of a header block, i.e. between `decode_fragment` and `finish`,
+    /// nor of a poisoned decoder.
+    pub fn snapshot(&self) -> Result<DecoderState, SnapshotError> {
+        if self.poisoned {
+            return Err(ContextPoisoned);
         }
 
-        // Return the header fields in the header_set
-        let mut hc = HeaderCollection::new();
-        for hf in self.header_set.get_header_fields().iter() {
-            hc.add(hf.key.clone(), hf.value.clone());
+        if self.state.offset > 0 {
+            return Err(BlockInProgress);
         }
-        Some(hc)
+
+        Ok(DecoderState {
+            max_table_size: self.header_table.get_max_size(),
+            entries: self.header_table.iter().map(|hf| hf.clone()).collect(),
+            max_header_list_size: self.max_header_list_size,
+            protocol_max_table_size: self.protocol_max_table_size,
+            size_update_required: self.size_update_required,
+            error_mode: self.error_mode.clone(),
+        })
     }
 
-    fn decode_indexed_literal(&mut self, mut header_block: ~[u8]) -> Option<~[u8]> {
-        let indexing = header_block[0] & 0x40 == 0x40;
+    /// Build a decoding context from a snapshot.
+    pub fn restore(state: &DecoderState) -> Result<Decoder, SnapshotError> {
+        propagate_err!(check_entries(state.entries.as_slice(), state.max_table_size));
 
-        let index_size =
-            if indexing {
-                6
-            } else {
-                4
-            };
-        let (index, buffer) = propagate_err!(decode_int(header_block, index_size));
-        let (value, buffer) = propagate_err!(self.read_string(buffer));
-        header_block = buffer;
+        // The header table may only exceed our setting until the peer signals a smaller size
+        if state.max_table_size > state.protocol_max_table_size && !state.size_update_required {
+            return Err(InvalidSnapshot(~"max_table_size exceeds protocol_max_table_size"));
+        }
 
-        let mut name;
+        let mut decoder = Decoder::new();
+        decoder.header_table.set_max_size(state.max_table_size);
 
-        if index > self.header_table.len() {
-            // Static header table index
-            let static_index = index - self.header_table.len();
-            let hf = propagate_err!(self.static_header_table.get(static_index));
-            name = hf.key;
-        } else {
-            // Header table index
-            let hf = propagate_err!(self.header_table.get(index));
-            name = hf.key;
+        // The oldest first
+        for hf in state.entries.rev_iter() {
+            decoder.header_table.add(hf.clone());
         }
 
-        let updated_header_field = HeaderField::new(name, propagate_err!(str::from_utf8_owned(value)));
+        decoder.max_header_list_size = state.max_header_list_size;
+        decoder.protocol_max_table_size = state.protocol_max_table_size;
+        decoder.size_update_required = state.size_update_required;
+        decoder.error_mode = state.error_mode.clone();
+
+        Ok(decoder)
+    }
 
-        if indexing {
-            self.header_set.emit(updated_header_field.clone());
-            self.header_table.add(updated_header_field.clone());
-            self.reference_set.add(updated_header_field.clone(), true);
+    /// Decode a headerblock into a set of header fields. Return a `DecoderError` if a decoding error has occurred.
+    pub fn decode(&mut self, header_block: ~[u8]) -> Result<HeaderCollection, DecoderError> {
+        let mut header_list = HeaderCollection::new();
 
+        let fields = propagate_err!(self.decode_slice(header_block.as_slice()));
 
-            self.evict();
-        } else {
-            self.header_set.emit(updated_header_field.clone());
+        for hf in fields.iter() {
+            if hf.sensitive {
+                header_list.add_sensitive(hf.key.as_slice().to_owned(), hf.value.as_slice().to_owned());
+            } else {
+                header_list.add(hf.key.as_slice().to_owned(), hf.value.as_slice().to_owned());
+            }
         }
 
-        Some(header_block)
+        Ok(header_list)
     }
 
-    fn decode_string_literal(&mut self, mut header_block: ~[u8]) -> Option<~[u8]> {
-        let indexing = header_block.shift().unwrap() == 0x40; // Remove the first octet and set indexing to true if 0100 0000 
+    /// Decode a complete headerblock without copying it.
+    /// The names and values of literals that are not Huffman encoded, and of
+    /// static header table entries, are borrowed from the header block and the static header table.
+    /// Return a `DecoderError` if a decoding error has occurred.
+    pub fn decode_slice<'a>(&mut self, header_block: &'a [u8]) -> Result<~[HeaderFieldRef<'a>], DecoderError> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
 
-        let (name, buffer)  = propagate_err!(self.read_string(header_block));
-        let (value, buffer) = propagate_err!(self.read_string(buffer));
+        self.state = BlockState::new();
 
-        header_block = buffer;
+        let result = match self.decode_representations(header_block) {
+            Ok(_) if self.size_update_required => Err(MissingTableSizeUpdate(header_block.len())),
+            result => result,
+        };
 
-        let hf = HeaderField::new(propagate_err!(str::from_utf8_owned(name)), propagate_err!(str::from_utf8_owned(value)));
+        self.end_block(result)
+    }
 
-        if indexing {
-            self.header_set.emit(hf.clone());
-            self.header_table.add(hf.clone());
-            self.reference_set.add(hf.clone(), true);
+    /// Decode the next fragment of a header block, e.g. the payload of a HEADERS
+    /// or CONTINUATION frame. Return the header fields completed by this fragment.
+    /// A representation split between two fragments is completed by the later one.
+    /// Call `finish` after the last fragment of the header block.
+    /// After an error the decoder starts over with a new header block.
+    pub fn decode_fragment(&mut self, fragment: &[u8]) -> Result<~[HeaderField], DecoderError> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
 
-            self.evict();
-        } else {
-            self.header_set.emit(hf.clone());
+        let mut fields = ~[];
+
+        for octet in fragment.iter() {
+            match self.decode_octet(*octet) {
+                Ok(Some(hf)) => {
+                    let offset = self.state.representation_offset;
+
+                    if self.count_field(hf.size(), offset) {
+                        fields.push(hf);
+                    }
+                },
+                Ok(None)     => {},
+                Err(e)       => return self.end_block(Err(e)),
+            }
         }
 
-        Some(header_block)
+        Ok(fields)
     }
 
-    fn decode_indexed_header(&mut self, mut header_block: ~[u8]) -> Option<~[u8]> {
-        let (index, buffer) = propagate_err!(decode_int(header_block, 7));
-        header_block = buffer;
+    /// End the current header block.
+    /// Return a `DecoderError` if the header block ended in the middle of a representation,
+    /// or `HeaderListTooLarge` if header fields of the header block have been dropped.
+    pub fn finish(&mut self) -> Result<(), DecoderError> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
 
-        if index > self.header_table.len() { // Look in static table
-            let static_index = index - self.header_table.len();
-            let hf = propagate_err!(self.static_header_table.get(static_index));
+        let result = match self.state.step {
+            FirstOctet if self.size_update_required => Err(MissingTableSizeUpdate(self.state.offset)),
+            FirstOctet            => Ok(()),
+            Prefix(_)             => Err(TruncatedInteger(self.state.representation_offset)),
+            StringStart           => Err(TruncatedString(self.state.offset)),
+            StringLength(_, _)    => Err(TruncatedInteger(self.state.string_offset)),
+            StringOctets(_, _, _) => Err(TruncatedString(self.state.string_offset)),
+        };
 
-            if self.reference_set.has(&hf) {
-                // "An _indexed representation_ corresponding to an entry _present_ in
-                // the reference set entails the following actions:
-                //
-                // o  The entry is removed from the reference set."
+        self.end_block(result)
+    }
 
-                match self.reference_set.remove(&hf) {
-                    false => return None,
-                    true => {},
+    // End the current header block. If it failed, its changes of the header table
+    // are undone, or the decoder is poisoned, depending on the error mode.
+    // A header list that is too large is only reported once the header block
+    // has been decoded without errors, and keeps its changes.
+    fn end_block<T>(&mut self, result: Result<T, DecoderError>) -> Result<T, DecoderError> {
+        let state = mem::replace(&mut self.state, BlockState::new());
+
+        match result {
+            Err(e) => {
+                match self.error_mode {
+                    RollBack => self.roll_back(state.changes),
+                    Poison   => self.poisoned = true,
                 }
-            } else {
-                // "*  The header field corresponding to the referenced entry is
-                //     emitted.
-                // 
-                //  *  The referenced static entry is inserted at the beginning of the
-                //     header table.
-                // 
-                //  *  A reference to this new header table entry is added to the
-                //     reference set (except if this new entry didn't fit in the
-                //     header table)."
-
-                self.header_set.emit(hf.clone());
-                self.header_table.add(hf.clone());
-
-                // We add the header field to the reference_set no matter what
-                // If the header field don't fit into the header_table we will
-                // remove it from the reference_set during the eviction
-
-                self.reference_set.add(hf.clone(), true);
-
-                self.evict();
+
+                Err(e)
+            },
+            Ok(value) => match state.list_too_large {
+                Some(offset) => Err(HeaderListTooLarge(offset)),
+                None         => Ok(value),
+            },
+        }
+    }
+
+    // Undo the changes of the header table, the latest first
+    fn roll_back(&mut self, changes: ~[Change]) {
+        for change in changes.move_rev_iter() {
+            match change {
+                Added => {
+                    self.header_table.remove_newest();
+                },
+                Evicted(field) => self.header_table.add_oldest(field),
+                Resized(max_size, size_update_required) => {
+                    self.header_table.set_max_size(max_size);
+                    self.size_update_required = size_update_required;
+                },
             }
+        }
+    }
 
-        } else { // Look in header table
-            let hf = propagate_err!(self.header_table.get(index));
+    // Decode all representations of a complete header block
+    fn decode_representations<'a>(&mut self, header_block: &'a [u8]) -> Result<~[HeaderFieldRef<'a>], DecoderError> {
+        let mut cursor = Cursor::new(header_block);
+        let mut fields = ~[];
 
-            if self.reference_set.has(&hf) {
-                // "An _indexed representation_ corresponding to an entry _present_ in
-                // the reference set entails the following actions:
-                //
-                // o  The entry is removed from the reference set."
+        while !cursor.is_empty() {
+            let offset = cursor.position;
+            let kind = propagate_err!(self.start_representation(cursor.peek(), offset));
 
-                match self.reference_set.remove(&hf) {
-                    false => return None,
-                    true => {},
+            match kind {
+                SizeUpdate => {
+                    let max_size = propagate_err!(cursor.read_int(kind.prefix_size()));
+                    propagate_err!(self.decode_context_update(max_size, offset));
+                },
+                IndexedField => {
+                    let index = propagate_err!(cursor.read_int(kind.prefix_size()));
+                    let hf = propagate_err!(self.get_header_field_ref(index), InvalidIndex(index, offset));
+
+                    if self.count_field(hf.size(), offset) {
+                        fields.push(hf);
+                    }
+                },
+                LiteralIndexedName(_) | LiteralNewName(_) => {
+                    let name = match kind {
+                        LiteralIndexedName(_) => {
+                            let index = propagate_err!(cursor.read_int(kind.prefix_size()));
+                            let hf = propagate_err!(self.get_header_field_ref(index), InvalidIndex(index, offset));
+
+                            hf.key
+                        },
+                        _ => {
+                            cursor.position += 1; // The first octet carries no index
+
+                            let name_offset = cursor.position;
+                            let (huffman_encoded, string) = propagate_err!(cursor.read_string());
+
+                            propagate_err!(self.decode_string_literal(huffman_encoded, string, name_offset))
+                        }
+                    };
+
+                    let value_offset = cursor.position;
+                    let (huffman_encoded, string) = propagate_err!(cursor.read_string());
+                    let value = propagate_err!(self.decode_string_literal(huffman_encoded, string, value_offset));
+
+                    let mut hf = HeaderFieldRef::new(name, value);
+                    hf.sensitive = kind.never_indexed();
+
+                    if kind.indexing() {
+                        self.insert(hf.to_header_field());
+                    }
+
+                    if self.count_field(hf.size(), offset) {
+                        fields.push(hf);
+                    }
                 }
-            } else {
-                // "*  The header field corresponding to the referenced entry is
-                //     emitted.
-                //
-                //  *  The referenced header table entry is added to the reference
-                //     set."
-
-                self.header_set.emit(hf.clone());
-                self.reference_set.add(hf.clone(), true);
             }
         }
 
-        Some(header_block)
+        Ok(fields)
     }
 
-    fn decode_context_update(&mut self, header_block: ~[u8]) -> Option<~[u8]> {
-        let (data, buffer) = propagate_err!(decode_int(header_block.clone(), 4));
+    // Add the size of a decoded header field to the size of the header list.
+    // Returns whether the header list is still within the maximum size, i.e. whether
+    // the header field is kept. The offset of the first one dropped is remembered.
+    fn count_field(&mut self, size: uint, offset: uint) -> bool {
+        self.state.list_size += size;
+
+        match self.max_header_list_size {
+            Some(max_size) if self.state.list_size > max_size => {
+                if self.state.list_too_large.is_none() {
+                    self.state.list_too_large = Some(offset);
+                }
+
+                false
+            },
+            _ => true
+        }
+    }
 
-        if header_block[0] & 0xF0 == 0x30 { // 0011 XXXX & 1111 0000 == 0011 0000
-            if data == 0 {
-                // Empty reference set
-                self.reference_set.empty();
+    // Classify the first octet of a representation and check that the
+    // representation may occur at this point of the header block
+    fn start_representation(&mut self, octet: u8, offset: uint) -> Result<Kind, DecoderError> {
+        if octet & 0xE0 == 0x20 {              // 001X XXXX = Dynamic Table Size Update
+            // "This dynamic table size update MUST occur at the beginning of
+            // the first header block following the change to the dynamic
+            // table size."
+            if self.state.field_seen {
+                return Err(UnexpectedTableSizeUpdate(offset));
             }
 
-            // Possibly other changes in the future of HPACK
-        } else {
-            // Change header table size
-            // TODO: Check that the new size is lower than or equal to SETTINGS_HEADER_TABLE_SIZE 
-            // http://tools.ietf.org/html/draft-ietf-httpbis-header-compression-07#section-4.4
-            self.header_table.set_max_size(data);
+            return Ok(SizeUpdate);
+        }
 
-            self.evict();
+        if self.size_update_required {
+            return Err(MissingTableSizeUpdate(offset));
         }
+        self.state.field_seen = true;
 
-        Some(buffer)
+        if octet >= 0x80 {                     // 1XXX XXXX = Indexed Header Field
+            return Ok(IndexedField);
+        }
+
+        let indexing = if octet & 0xC0 == 0x40 { // 01XX XXXX = with Incremental Indexing
+            Incremental
+        } else if octet & 0xF0 == 0x10 {         // 0001 XXXX = never Indexed
+            NeverIndexed
+        } else {                                 // 0000 XXXX = without Indexing
+            WithoutIndexing
+        };
+
+        if octet == 0x00 ||                      // 0000 0000 = Literal Header Field - New Name
+           octet == 0x40 ||                      // 0100 0000 = Literal Header Field - New Name
+           octet == 0x10 {                       // 0001 0000 = Literal Header Field never Indexed - New Name
+            Ok(LiteralNewName(indexing))
+        } else {                                 // 01XX XXXX, 0000 XXXX or 0001 XXXX = Literal Header Field - Indexed Name
+            Ok(LiteralIndexedName(indexing))
+        }
     }
 
-    /*
-     * Reads a string from the header block (and consumes it)
-     */
-    fn read_string(&mut self, mut header_block: ~[u8]) -> Option<(~[u8], ~[u8])> { 
-        let mut string;        
+    // Decode a single octet of the header block.
+    // Returns the header field if the octet completed one.
+    fn decode_octet(&mut self, octet: u8) -> Result<Option<HeaderField>, DecoderError> {
+        let offset = self.state.offset;
+        self.state.offset += 1;
+
+        match mem::replace(&mut self.state.step, FirstOctet) {
+            FirstOctet => {
+                self.state.representation_offset = offset;
+                self.state.kind = propagate_err!(self.start_representation(octet, offset));
+
+                match self.state.kind {
+                    LiteralNewName(_) => {
+                        self.state.step = StringStart;
+                        Ok(None)
+                    },
+                    kind => self.decode_prefix(IntegerDecoder::new(kind.prefix_size()), octet),
+                }
+            },
+            Prefix(decoder) => self.decode_prefix(decoder, octet),
+            StringStart => {
+                self.state.string_offset = offset;
+
+                let huffman_encoded = octet & 0x80 == 0x80; // 1XXX XXXX & 1000 0000 == 1000 0000
+                self.decode_string_length(huffman_encoded, IntegerDecoder::new(7), octet)
+            },
+            StringLength(huffman_encoded, decoder) => self.decode_string_length(huffman_encoded, decoder, octet),
+            StringOctets(huffman_encoded, length, mut string) => {
+                string.push(octet);
+
+                if string.len() < length {
+                    self.state.step = StringOctets(huffman_encoded, length, string);
+                    return Ok(None);
+                }
 
-        let huffman_encoded = header_block[0] & 0x80 == 0x80; // 1XXX XXXX & 1000 0000 == 1000 0000
-        let (string_length, buffer) = propagate_err!(decode_int(header_block, 7));
-        header_block = buffer;
+                self.decode_string(huffman_encoded, string)
+            },
+        }
+    }
+
+    // Continue the index or size starting the current representation
+    fn decode_prefix(&mut self, mut decoder: IntegerDecoder, octet: u8) -> Result<Option<HeaderField>, DecoderError> {
+        let offset = self.state.representation_offset;
 
+        let value = match propagate_err!(integer_result(decoder.push(octet), offset)) {
+            Some(value) => value,
+            None => {
+                self.state.step = Prefix(decoder);
+                return Ok(None);
+            }
+        };
+
+        match self.state.kind {
+            IndexedField => {
+                // "An indexed header field representation identifies an entry in
+                // either the static table or the dynamic table.  It causes a header
+                // field to be added to the decoded header list."
+                let hf = propagate_err!(self.get_header_field(value), InvalidIndex(value, offset));
+
+                Ok(Some(hf))
+            },
+            LiteralIndexedName(_) => {
+                let hf = propagate_err!(self.get_header_field(value), InvalidIndex(value, offset));
+                self.state.name = Some(hf.key);
+                self.state.step = StringStart;
+
+                Ok(None)
+            },
+            SizeUpdate => {
+                propagate_err!(self.decode_context_update(value, offset));
+
+                Ok(None)
+            },
+            LiteralNewName(_) => unreachable!(),
+        }
+    }
+
+    // Continue the length of the current string literal
+    fn decode_string_length(&mut self, huffman_encoded: bool, mut decoder: IntegerDecoder, octet: u8) -> Result<Option<HeaderField>, DecoderError> {
+        let offset = self.state.string_offset;
+
+        match propagate_err!(integer_result(decoder.push(octet), offset)) {
+            Some(0) => self.decode_string(huffman_encoded, ~[]),
+            Some(length) => {
+                self.state.step = StringOctets(huffman_encoded, length, ~[]);
+                Ok(None)
+            },
+            None => {
+                self.state.step = StringLength(huffman_encoded, decoder);
+                Ok(None)
+            }
+        }
+    }
+
+    // A string literal is complete.
+    // It is either the name of a new name literal or the value of a literal.
+    fn decode_string(&mut self, huffman_encoded: bool, string: ~[u8]) -> Result<Option<HeaderField>, DecoderError> {
+        let offset = self.state.string_offset;
+
+        let decoded_string = propagate_err!(self.decode_string_literal(huffman_encoded, string.as_slice(), offset)).into_owned();
+
+        let name = match self.state.name.take() {
+            Some(name) => name,
+            None => {
+                // The name of a new name literal, the value follows
+                self.state.name = Some(decoded_string);
+                self.state.step = StringStart;
+                return Ok(None);
+            }
+        };
+
+        let mut hf = HeaderField::from_bytes(name, decoded_string);
+        hf.sensitive = self.state.kind.never_indexed();
+
+        if self.state.kind.indexing() {
+            self.insert(hf.clone());
+        }
+
+        Ok(Some(hf))
+    }
+
+    // Undo the Huffman encoding of a string literal (if any).
+    // Only a Huffman encoded string is copied.
+    fn decode_string_literal<'a>(&mut self, huffman_encoded: bool, string: &'a [u8], offset: uint) -> Result<MaybeOwnedBytes<'a>, DecoderError> {
         if huffman_encoded {
-            let decoded_string_opt = self.huffman_decoder.decode(header_block.slice(0, string_length).to_owned());
-            string = propagate_err!(decoded_string_opt);
+            let decoded_string = match self.huffman_decoder.decode(string) {
+                Ok(decoded_string)  => decoded_string,
+                Err(EosSymbol)      => return Err(InvalidHuffmanCode(offset)),
+                Err(InvalidPadding) => return Err(InvalidHuffmanPadding(offset)),
+            };
+
+            Ok(Owned(decoded_string))
         } else {
-            string = header_block.slice(0, string_length).to_owned();
+            Ok(Slice(string))
+        }
+    }
+
+    fn decode_context_update(&mut self, max_size: uint, offset: uint) -> Result<(), DecoderError> {
+        // "The new maximum size MUST be lower than or equal to the limit
+        // determined by the protocol using HPACK."
+        if max_size > self.protocol_max_table_size {
+            return Err(TableSizeUpdateTooLarge(max_size, offset));
         }
 
-        for _ in range(0, string_length) {
-            header_block.shift();
+        // "A dynamic table size update signals a change to the size of the
+        // dynamic table."
+        self.state.changes.push(Resized(self.header_table.get_max_size(), self.size_update_required));
+        self.header_table.set_max_size(max_size);
+        self.size_update_required = false;
+
+        self.evict();
+
+        Ok(())
+    }
+
+    // The header field at an index of the static table or the header table,
+    // or None if the index is 0 or out of bounds
+    // "Indices strictly greater than the length of the static table refer
+    // to elements in the dynamic table."
+    fn get_header_field(&self, index: uint) -> Option<HeaderField> {
+        if index > self.static_header_table.len() {
+            self.header_table.get(index - self.static_header_table.len())
+        } else {
+            self.static_header_table.get(index)
+        }
+    }
+
+    // Same as get_header_field, but nothing is copied: static header table entries
+    // are borrowed and header table entries are shared
+    fn get_header_field_ref<'a>(&mut self, index: uint) -> Option<HeaderFieldRef<'a>> {
+        if index > self.static_header_table.len() {
+            match self.header_table.get_shared(index - self.static_header_table.len()) {
+                Some(hf) => Some(HeaderFieldRef::new(SharedKey(hf.clone()), SharedValue(hf))),
+                None     => None,
+            }
+        } else {
+            match self.static_header_table.get_ref(index) {
+                Some((name, value)) => Some(HeaderFieldRef::new(Slice(name.as_bytes()), Slice(value.as_bytes()))),
+                None                => None,
+            }
         }
+    }
+
+    // Add a header field to the header table, evicting old entries if needed
+    fn insert(&mut self, hf: HeaderField) {
+        self.header_table.add(hf);
+        self.state.changes.push(Added);
 
-        Some((string, header_block))
+        self.evict();
     }
 
     // Evict header fields until the header table is wihtin its allowed size.
-    // We remove the oldest header field (remember, we add from the front)
-    // and also remove it from the reference set.
+    // We remove the oldest header field (remember, we add from the front).
     fn evict(&mut self) {
         while self.header_table.size() > self.header_table.get_max_size() && self.header_table.len() > 0 {
-            let header_table_length = self.header_table.len();
             // If this unwrap fails, something is rotten in Denmark
-            // I.e. We assume it is always within bounds to remove the last element 
-            let removed_header_field = self.header_table.remove(header_table_length).unwrap();
-            self.reference_set.remove(&removed_header_field);
+            // I.e. We assume it is always within bounds to remove the last element
+            let field = self.header_table.remove_oldest().unwrap();
+            self.state.changes.push(Evicted(field));
         }
     }
 }
 
+// Look up an index in the context of a decoder, without decoding anything
+// The module is private, so this is only used by the disassembler.
+pub fn lookup(decoder: &Decoder, index: uint) -> Option<HeaderField> {
+    decoder.get_header_field(index)
+}
+
+// Map the result of decoding an integer starting at offset
+// An IntegerDecoder fed one octet at a time only fails on overflow
+fn integer_result(result: Result<Option<u64>, IntegerError>, offset: uint) -> Result<Option<uint>, DecoderError> {
+    match result {
+        // IntegerDecoder::new only decodes integers that fit into an uint
+        Ok(Some(value)) => Ok(Some(value as uint)),
+        Ok(None)        => Ok(None),
+        Err(_)          => Err(IntegerOverflow(offset)),
+    }
+}
+
 #[cfg(test)]
 mod decode_test {
-    use integer_representation::encode_int; 
-    use decoder::Decoder;
-    use header_field::HeaderField;
+    use integer::encode_int;
+    use decoder::{Decoder, InvalidIndex, TruncatedInteger, TruncatedString};
+    use decoder::{InvalidHuffmanCode, InvalidHuffmanPadding};
+    use decoder::{TableSizeUpdateTooLarge, UnexpectedTableSizeUpdate, MissingTableSizeUpdate};
+    use decoder::HeaderListTooLarge;
+    use decoder::{Poison, Poisoned};
+    use header_field::{HeaderField, Slice, SharedKey, SharedValue};
 
 
     #[test]
     fn decode_test() {
         let mut decoder = Decoder::new();
-        
+
         let mut index = encode_int(5, 7);
         index[0] += 128; // Flip the first bit
 
@@ -305,7 +853,7 @@ mod decode_test {
 
         let header_fields = decoder.decode(frame0).unwrap();
         let h0 = HeaderField::new(~":path", ~"/index.html");
-        assert!(header_fields.get(&h0.key)[0] == h0.value);
+        assert!(header_fields.get(h0.key.as_slice())[0] == h0.value);
 
 
 
@@ -322,26 +870,373 @@ mod decode_test {
 
         let header_fields = decoder.decode(frame1).unwrap();
 
-        assert!(header_fields.get(&h0.key)[0] == h0.value);
-        assert!(header_fields.get(&h1.key)[0] == h1.value);
+        // Nothing is emitted implicitly from earlier header blocks
+        assert!(header_fields.get(h0.key.as_slice()).len() == 0);
+        assert!(header_fields.get(h1.key.as_slice())[0] == h1.value);
 
 
 
+        // Index 62 is the first entry of the dynamic table (foo: bar)
         let h2 = HeaderField::new(~"foo", ~"baz");
-        let index = encode_int(1, 6);
+        let mut indexed = encode_int(62, 7);
+        indexed[0] |= 0x80;
+        let index = encode_int(62, 4);
         let value = ~"baz";
         let value_length = encode_int(value.clone().len(), 7);
         let mut frame2: ~[u8] = ~[];
-        frame2.push_all_move(~[0x81]);
+        frame2.push_all_move(indexed);
         frame2.push_all_move(index);
         frame2.push_all_move(value_length);
         frame2.push_all_move(value.into_bytes());
 
         let header_fields = decoder.decode(frame2).unwrap();
 
-        assert!(header_fields.get(&h0.key)[0] == h0.value);
-        assert!(header_fields.get(&h2.key)[0] == h2.value);
-        println!("{}", header_fields.get(&h1.key).to_str());
-        assert!(header_fields.get(&h1.key).len() == 1 && header_fields.get(&h1.key)[0] == h2.value);
+        assert!(header_fields.get(h0.key.as_slice()).len() == 0);
+        assert!(header_fields.get(h1.key.as_slice()).len() == 2);
+        assert!(header_fields.get(h1.key.as_slice())[0] == h1.value);
+        assert!(header_fields.get(h2.key.as_slice())[1] == h2.value);
+    }
+
+    #[test]
+    fn decode_context_update_test() {
+        let mut decoder = Decoder::new();
+
+        // Literal with incremental indexing - new name (foo: bar)
+        let frame0 = ~[0x40, 3, 102, 111, 111, 3, 98, 97, 114];
+        decoder.decode(frame0).unwrap();
+
+        // Shrink the dynamic table to 0 octets (evicts foo: bar)
+        // and reference the evicted entry afterwards
+        let mut frame1 = encode_int(0, 5);
+        frame1[0] |= 0x20;
+        frame1.push(0x80 | 62);
+
+        assert!(decoder.decode(frame1) == Err(InvalidIndex(62, 1)));
+    }
+
+    #[test]
+    fn decode_error_test() {
+        let mut decoder = Decoder::new();
+
+        // Index 0 is never valid
+        assert!(decoder.decode(~[0x80]) == Err(InvalidIndex(0, 0)));
+
+        // The index is not complete
+        assert!(decoder.decode(~[0x82, 0xFF]) == Err(TruncatedInteger(1)));
+
+        // The value claims 3 octets but only 2 follow
+        assert!(decoder.decode(~[0x82, 0x04, 3, 98, 97]) == Err(TruncatedString(2)));
+
+        // Names and values are octets, and need not be valid UTF-8
+        let fields = decoder.decode_slice(&[0x00, 1, 0xFF, 1, 0xE6]).unwrap();
+        assert!(fields[0].to_header_field() == HeaderField::from_bytes(~[0xFF], ~[0xE6]));
+        assert!(fields[0].key_str().is_none() && fields[0].value_str().is_none());
+
+        // The value contains the EOS symbol
+        assert!(decoder.decode(~[0x04, 0x84, 0xFF, 0xFF, 0xFF, 0xFF]) == Err(InvalidHuffmanCode(1)));
+
+        // The value is padded with 000 instead of 111
+        assert!(decoder.decode(~[0x04, 0x81, 0x18]) == Err(InvalidHuffmanPadding(1)));
+        assert!(decoder.decode_fragment(&[0x04, 0x81, 0x18]) == Err(InvalidHuffmanPadding(1)));
+    }
+
+    #[test]
+    fn set_protocol_max_table_size_test() {
+        let mut decoder = Decoder::new();
+
+        // A size update above the advertised limit
+        let mut frame0 = encode_int(4097, 5);
+        frame0[0] |= 0x20;
+        assert!(decoder.decode(frame0) == Err(TableSizeUpdateTooLarge(4097, 0)));
+
+        // A size update after a header field representation
+        assert!(decoder.decode(~[0x82, 0x20]) == Err(UnexpectedTableSizeUpdate(1)));
+
+        // Lowering the setting requires a size update in the next header block
+        decoder.set_protocol_max_table_size(100);
+        assert!(decoder.decode(~[0x82]) == Err(MissingTableSizeUpdate(0)));
+        assert!(decoder.decode(~[]) == Err(MissingTableSizeUpdate(0)));
+
+        let mut frame1 = encode_int(101, 5);
+        frame1[0] |= 0x20;
+        assert!(decoder.decode(frame1) == Err(TableSizeUpdateTooLarge(101, 0)));
+
+        // Two size updates at the beginning of a header block are fine
+        let mut frame2 = encode_int(0, 5);
+        frame2[0] |= 0x20;
+        let mut size_update = encode_int(100, 5);
+        size_update[0] |= 0x20;
+        frame2.push_all_move(size_update);
+        frame2.push(0x82);
+        let header_fields = decoder.decode(frame2).unwrap();
+        assert!(header_fields.get(bytes!(":method"))[0].as_slice() == bytes!("GET"));
+
+        // The requirement is met, so later header blocks need no size update
+        assert!(decoder.decode(~[0x82]).is_ok());
+
+        // Raising the setting does not require a size update
+        decoder.set_protocol_max_table_size(4096);
+        assert!(decoder.decode(~[0x82]).is_ok());
+    }
+
+    #[test]
+    fn decode_fragment_test() {
+        let mut decoder = Decoder::new();
+
+        // :method: GET, then custom-key: custom-header with incremental indexing
+        let block: ~[u8] = ~[0x82,
+                             0x40, 10, 99, 117, 115, 116, 111, 109, 45, 107, 101, 121,
+                             13, 99, 117, 115, 116, 111, 109, 45, 104, 101, 97, 100, 101, 114];
+
+        // Split the header block at every possible position
+        for split in range(0, block.len() + 1) {
+            let mut decoder = Decoder::new();
+
+            let mut fields = decoder.decode_fragment(block.slice(0, split)).unwrap();
+            fields.push_all_move(decoder.decode_fragment(block.slice(split, block.len())).unwrap());
+            decoder.finish().unwrap();
+
+            assert!(fields.len() == 2);
+            assert!(fields[0] == HeaderField::new(~":method", ~"GET"));
+            assert!(fields[1] == HeaderField::new(~"custom-key", ~"custom-header"));
+        }
+
+        // A field is handed out as soon as it is complete
+        let fields = decoder.decode_fragment(block.slice(0, 3)).unwrap();
+        assert!(fields.len() == 1);
+        assert!(fields[0] == HeaderField::new(~":method", ~"GET"));
+
+        let fields = decoder.decode_fragment(block.slice(3, block.len())).unwrap();
+        assert!(fields.len() == 1);
+        assert!(decoder.finish().is_ok());
+
+        // The new entry (index 62) can be used from the next header block
+        let fields = decoder.decode_fragment(&[0x80 | 62]).unwrap();
+        assert!(fields[0] == HeaderField::new(~"custom-key", ~"custom-header"));
+        assert!(decoder.finish().is_ok());
+
+        // An index (15 + 47 = 62) split across fragments
+        assert!(decoder.decode_fragment(&[0x0F]).unwrap().len() == 0);
+        let fields = decoder.decode_fragment(&[47, 1, 97]).unwrap();
+        assert!(fields[0] == HeaderField::new(~"custom-key", ~"a"));
+        assert!(decoder.finish().is_ok());
+
+        // The header block must end on a representation boundary
+        decoder.decode_fragment(block.slice(0, 5)).unwrap();
+        assert!(decoder.finish() == Err(TruncatedString(2)));
+
+        decoder.decode_fragment(&[0xFF]).unwrap();
+        assert!(decoder.finish() == Err(TruncatedInteger(0)));
+
+        // After an error the decoder starts over
+        assert!(decoder.decode_fragment(&[0x80]) == Err(InvalidIndex(0, 0)));
+        assert!(decoder.decode_fragment(&[0x82]).unwrap().len() == 1);
+        assert!(decoder.finish().is_ok());
+    }
+
+    #[test]
+    fn decode_slice_test() {
+        let mut decoder = Decoder::new();
+
+        // :method: GET, custom-key: custom-header with incremental indexing
+        // and :path: /sample/path without indexing
+        let block: ~[u8] = ~[0x82,
+                             0x40, 10, 99, 117, 115, 116, 111, 109, 45, 107, 101, 121,
+                             13, 99, 117, 115, 116, 111, 109, 45, 104, 101, 97, 100, 101, 114,
+                             0x04, 12, 47, 115, 97, 109, 112, 108, 101, 47, 112, 97, 116, 104];
+
+        let fields = decoder.decode_slice(block.as_slice()).unwrap();
+        assert!(fields.len() == 3);
+
+        // Static header table entries and plain literals are borrowed
+        match fields[0].key {
+            Slice(key) => assert!(key == bytes!(":method")),
+            _          => fail!("The static header table entry was copied"),
+        }
+        match fields[1].value {
+            Slice(value) => assert!(value.as_ptr() == block.slice_from(14).as_ptr()),
+            _            => fail!("The literal was copied"),
+        }
+        assert!(fields[2].to_header_field() == HeaderField::new(~":path", ~"/sample/path"));
+
+        // The header table holds its own copy, which an indexed header field shares
+        let fields = decoder.decode_slice(&[0x80 | 62]).unwrap();
+        assert!(fields[0].to_header_field() == HeaderField::new(~"custom-key", ~"custom-header"));
+
+        let entry = decoder.header_table().get(1).unwrap();
+        match fields[0].key {
+            SharedKey(_) => assert!(fields[0].key.as_slice().as_ptr() == entry.key.as_ptr()),
+            _            => fail!("The header table entry was copied"),
+        }
+        match fields[0].value {
+            SharedValue(_) => assert!(fields[0].value.as_slice().as_ptr() == entry.value.as_ptr()),
+            _              => fail!("The header table entry was copied"),
+        }
+    }
+
+    #[test]
+    fn max_header_list_size_test() {
+        let mut decoder = Decoder::new();
+
+        // :method: GET has a size of 3 + 7 + 32 = 42
+        let block: ~[u8] = ~[0x82, 0x82, 0x82];
+        assert!(decoder.decode_slice(block.as_slice()).unwrap().len() == 3);
+
+        decoder.set_max_header_list_size(100);
+        assert!(decoder.decode_slice(block.slice_to(2)).unwrap().len() == 2);
+        assert!(decoder.decode_slice(block.as_slice()).err() == Some(HeaderListTooLarge(2)));
+        assert!(decoder.decode(block.clone()).err() == Some(HeaderListTooLarge(2)));
+
+        // The size is counted across fragments, and starts over with the next header block
+        assert!(decoder.decode_fragment(block.slice_to(2)).unwrap().len() == 2);
+        assert!(decoder.decode_fragment(block.slice_from(2)).unwrap().len() == 0);
+        assert!(decoder.finish().err() == Some(HeaderListTooLarge(2)));
+
+        assert!(decoder.decode_fragment(block.slice_to(2)).unwrap().len() == 2);
+        decoder.finish().unwrap();
+
+        // Literals count as well: foo: bar has a size of 38
+        decoder.set_max_header_list_size(80);
+        let literal: ~[u8] = ~[0x00, 3, 102, 111, 111, 3, 98, 97, 114];
+        let mut block = literal.clone();
+        block.push(0x82);
+        assert!(decoder.decode_slice(block.as_slice()).unwrap().len() == 2);
+        block.push_all(literal.as_slice());
+        assert!(decoder.decode_slice(block.as_slice()).err() == Some(HeaderListTooLarge(10)));
+    }
+
+    #[test]
+    fn header_list_too_large_keeps_table_test() {
+        let mut decoder = Decoder::new();
+        decoder.set_error_mode(Poison);
+        decoder.set_max_header_list_size(50);
+
+        // :method: GET, then foo: bar with incremental indexing beyond the maximum size
+        let block: ~[u8] = ~[0x82, 0x40, 3, 102, 111, 111, 3, 98, 97, 114];
+        assert!(decoder.decode_slice(block.as_slice()).err() == Some(HeaderListTooLarge(1)));
+
+        // foo: bar has been dropped, but added to the header table nonetheless
+        assert!(!decoder.is_poisoned());
+        assert!(decoder.header_table.len() == 1);
+
+        // so the following header block can refer to it
+        let fields = decoder.decode_slice(&[0x80 | 62]).unwrap();
+        assert!(fields[0].to_header_field() == HeaderField::new(~"foo", ~"bar"));
+
+        // Likewise when the header block is decoded in fragments
+        let fields = decoder.decode_fragment(block.as_slice()).unwrap();
+        assert!(fields == ~[HeaderField::new(~":method", ~"GET")]);
+        assert!(decoder.finish().err() == Some(HeaderListTooLarge(1)));
+        assert!(decoder.header_table.len() == 2);
+
+        let fields = decoder.decode_fragment(&[0x80 | 63]).unwrap();
+        assert!(fields == ~[HeaderField::new(~"foo", ~"bar")]);
+        assert!(decoder.finish().is_ok());
+    }
+
+    #[test]
+    fn never_indexed_test() {
+        let mut decoder = Decoder::new();
+
+        // RFC 7541 C.2.3: password: secret never indexed (new name)
+        // followed by :path: / never indexed (indexed name)
+        let block: ~[u8] = ~[0x10, 8, 112, 97, 115, 115, 119, 111, 114, 100, 6, 115, 101, 99, 114, 101, 116,
+                             0x14, 1, 47];
+
+        let fields = decoder.decode_slice(block.as_slice()).unwrap();
+        assert!(fields[0].sensitive && fields[1].sensitive);
+        assert!(fields[0].to_header_field() == HeaderField::new(~"password", ~"secret"));
+        assert!(fields[1].to_header_field() == HeaderField::new(~":path", ~"/"));
+        assert!(fields[0].to_header_field().sensitive);
+
+        // Nothing is added to the header table
+        assert!(decoder.header_table.len() == 0);
+
+        let fields = decoder.decode_fragment(block.as_slice()).unwrap();
+        decoder.finish().unwrap();
+        assert!(fields[0] == HeaderField::new(~"password", ~"secret"));
+        assert!(fields[1] == HeaderField::new(~":path", ~"/"));
+        assert!(fields[0].sensitive && fields[1].sensitive);
+
+        let header_list = decoder.decode(block.clone()).unwrap();
+        assert!(header_list.is_sensitive(bytes!("password"), bytes!("secret")));
+        assert!(header_list.is_sensitive(bytes!(":path"), bytes!("/")));
+        assert!(header_list.get(bytes!("password"))[0].as_slice() == bytes!("secret"));
+
+        // Literals without indexing are not sensitive
+        let fields = decoder.decode_slice(&[0x04, 1, 47]).unwrap();
+        assert!(!fields[0].sensitive);
+    }
+
+    #[test]
+    fn roll_back_test() {
+        // foo: bar with incremental indexing
+        let frame0: ~[u8] = ~[0x40, 3, 102, 111, 111, 3, 98, 97, 114];
+
+        // A size update to 40 octets, baz: qux with incremental indexing (evicts foo: bar),
+        // and a reference to index 70, which does not exist
+        let frame1: ~[u8] = ~[0x3f, 0x09, 0x40, 3, 98, 97, 122, 3, 113, 117, 120, 0x80 | 70];
+
+        for split in range(0, frame1.len()) {
+            let mut decoder = Decoder::with_max_table_size(80);
+            decoder.decode_slice(frame0.as_slice()).unwrap();
+
+            if split == 0 {
+                assert!(decoder.decode_slice(frame1.as_slice()).err() == Some(InvalidIndex(70, 11)));
+            } else {
+                decoder.decode_fragment(frame1.slice_to(split)).unwrap();
+                assert!(decoder.decode_fragment(frame1.slice_from(split)) == Err(InvalidIndex(70, 11)));
+            }
+
+            // The header table is as before the failed header block
+            assert!(decoder.header_table().len() == 1);
+            assert!(decoder.header_table().size() == 38);
+            assert!(decoder.header_table().max_size() == 80);
+            assert!(!decoder.is_poisoned());
+
+            let fields = decoder.decode_slice(&[0x80 | 62]).unwrap();
+            assert!(fields[0].to_header_field() == HeaderField::new(~"foo", ~"bar"));
+        }
+
+        // A header block which fails to end is rolled back as well
+        let mut decoder = Decoder::new();
+        decoder.decode_fragment(frame0.as_slice()).unwrap();
+        decoder.decode_fragment(&[0xFF]).unwrap();
+        assert!(decoder.finish() == Err(TruncatedInteger(9)));
+        assert!(decoder.header_table().len() == 0);
+
+        // So is a size update of a header block which lacks a required one
+        decoder.set_protocol_max_table_size(100);
+        let mut frame2 = encode_int(0, 5);
+        frame2[0] |= 0x20;
+        frame2.push(0x80);
+        assert!(decoder.decode_slice(frame2.as_slice()).err() == Some(InvalidIndex(0, 1)));
+        assert!(decoder.header_table().max_size() == 4096);
+        assert!(decoder.decode_slice(&[0x82]).err() == Some(MissingTableSizeUpdate(0)));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn poison_test() {
+        let mut decoder = Decoder::new();
+        decoder.set_error_mode(Poison);
+
+        // foo: bar with incremental indexing, followed by index 0
+        let block: &[u8] = &[0x40, 3, 102, 111, 111, 3, 98, 97, 114, 0x80];
+        assert!(decoder.decode_slice(block).err() == Some(InvalidIndex(0, 9)));
+        assert!(decoder.is_poisoned());
+
+        // The header table keeps foo: bar, but nothing is decoded any more
+        assert!(decoder.header_table().len() == 1);
+        assert!(decoder.decode_slice(&[0x82]).err() == Some(Poisoned));
+        assert!(decoder.decode(~[0x82]) == Err(Poisoned));
+        assert!(decoder.decode_fragment(&[0x82]) == Err(Poisoned));
+        assert!(decoder.finish() == Err(Poisoned));
+
+        // Until the context is reset
+        decoder.reset();
+        assert!(!decoder.is_poisoned());
+        assert!(decoder.header_table().len() == 0);
+        assert!(decoder.decode_slice(&[0x82]).unwrap().len() == 1);
+        assert!(decoder.decode_slice(&[0x80 | 62]).err() == Some(InvalidIndex(62, 0)));
+    }
+}
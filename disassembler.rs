@@ -0,0 +1,242 @@
+// Turning a header block back into its representations, e.g. to find out why
+// a peer's header blocks are rejected.
+//
+// `disassemble` only parses a header block. It needs no decoding context, and
+// string literals are kept as they are on the wire (possibly Huffman encoded).
+// `resolve` then decodes the representations one by one with a decoding context,
+// and tells which table entries they refer to, and what they add to or evict
+// from the header table.
+use decoder::{Decoder, DecoderError, Cursor, lookup};
+use header_field::HeaderField;
+use representation::{IndexedHeader, IndexedLiteral, NamedLiteral, ContextUpdate};
+
+/// A representation of a header block.
+#[deriving(Eq, Show, Clone)]
+pub enum Parsed {
+    /// Indexed Header Field
+    IndexedHeaderField(IndexedHeader),
+    /// Literal Header Field with an indexed name
+    IndexedNameLiteral(IndexedLiteral),
+    /// Literal Header Field with a new name
+    NewNameLiteral(NamedLiteral),
+    /// Dynamic Table Size Update
+    SizeUpdate(ContextUpdate),
+}
+
+/// A representation, and the range `start..end` of the header block it was parsed from.
+#[deriving(Eq, Show, Clone)]
+pub struct Disassembled {
+    start: uint,
+    end: uint,
+    representation: Parsed,
+}
+
+/// The effect of decoding a representation with a decoding context.
+#[deriving(Eq, Show, Clone)]
+pub struct Resolved {
+    /// The header field it decoded into. None for a Dynamic Table Size Update.
+    field: Option<HeaderField>,
+    /// The index and the entry of the static table or the header table it refers to:
+    /// the header field of an Indexed Header Field, or the name of a literal with an indexed name.
+    referenced: Option<(uint, HeaderField)>,
+    /// The header field added to the header table.
+    inserted: Option<HeaderField>,
+    /// The header fields evicted from the header table, the oldest first.
+    evicted: ~[HeaderField],
+}
+
+/// Parse a header block into its representations, in order.
+/// Indices and sizes are not checked against any table.
+/// If a representation cannot be parsed, return the representations before it, and the
+/// `DecoderError` (`TruncatedInteger`, `TruncatedString` or `IntegerOverflow`) it failed with.
+pub fn disassemble(header_block: &[u8]) -> (~[Disassembled], Option<DecoderError>) {
+    let mut cursor = Cursor::new(header_block);
+    let mut representations = ~[];
+
+    while !cursor.is_empty() {
+        let start = cursor.position;
+
+        let representation = match parse_representation(&mut cursor) {
+            Ok(representation) => representation,
+            Err(e)             => return (representations, Some(e)),
+        };
+
+        representations.push(Disassembled {
+            start: start,
+            end: cursor.position,
+            representation: representation,
+        });
+    }
+
+    (representations, None)
+}
+
+// Parse the representation at the position of the cursor
+fn parse_representation(cursor: &mut Cursor) -> Result<Parsed, DecoderError> {
+    let octet = cursor.peek();
+
+    let representation = if octet >= 0x80 {        // 1XXX XXXX = Indexed Header Field
+        IndexedHeaderField(IndexedHeader::new(propagate_err!(cursor.read_int(7))))
+    } else if octet & 0xE0 == 0x20 {              // 001X XXXX = Dynamic Table Size Update
+        SizeUpdate(ContextUpdate::new(propagate_err!(cursor.read_int(5))))
+    } else {
+        // 01XX XXXX = with Incremental Indexing, 0001 XXXX = never Indexed, 0000 XXXX = without Indexing
+        let indexing = octet & 0xC0 == 0x40;
+        let never_indexed = octet & 0xF0 == 0x10;
+        let index = propagate_err!(cursor.read_int(if indexing { 6 } else { 4 }));
+
+        if index == 0 {
+            let (name_huffman, name) = propagate_err!(cursor.read_string());
+            let (value_huffman, value) = propagate_err!(cursor.read_string());
+
+            NewNameLiteral(NamedLiteral::new(indexing, never_indexed, name_huffman, name.to_owned(), value_huffman, value.to_owned()))
+        } else {
+            let (value_huffman, value) = propagate_err!(cursor.read_string());
+
+            IndexedNameLiteral(IndexedLiteral::new(indexing, never_indexed, index, value_huffman, value.to_owned()))
+        }
+    };
+
+    Ok(representation)
+}
+
+/// Resolve every representation of a header block in order, and end the header block.
+/// See `Disassembled::resolve`.
+pub fn resolve(header_block: &[u8], representations: &[Disassembled], decoder: &mut Decoder) -> Result<~[Resolved], DecoderError> {
+    let mut resolved = ~[];
+
+    for representation in representations.iter() {
+        resolved.push(propagate_err!(representation.resolve(header_block, decoder)));
+    }
+
+    propagate_err!(decoder.finish());
+
+    Ok(resolved)
+}
+
+impl Disassembled {
+    /// The octets of the header block the representation was parsed from.
+    pub fn octets<'a>(&self, header_block: &'a [u8]) -> &'a [u8] {
+        header_block.slice(self.start, self.end)
+    }
+
+    /// Decode the representation with a decoding context, which is updated as by `Decoder::decode`.
+    /// The representations of a header block must be resolved in order, and `Decoder::finish`
+    /// be called after the last one. Return the `DecoderError` the decoder rejects it with.
+    pub fn resolve(&self, header_block: &[u8], decoder: &mut Decoder) -> Result<Resolved, DecoderError> {
+        // An index out of bounds is rejected by the decoder below
+        let referenced = match self.representation {
+            IndexedHeaderField(ref header) => lookup(&*decoder, header.index).map(|hf| (header.index, hf)),
+            IndexedNameLiteral(ref literal) => lookup(&*decoder, literal.index).map(|hf| {
+                (literal.index, HeaderField::from_bytes(hf.key, ~[]))
+            }),
+            _ => None,
+        };
+
+        // The entries that may be evicted, the newest first
+        let entries: ~[HeaderField] = decoder.header_table().iter().map(|hf| hf.clone()).collect();
+
+        let fields = propagate_err!(decoder.decode_fragment(self.octets(header_block)));
+        let field = fields.move_iter().next();
+
+        let table = decoder.header_table();
+
+        // A header field larger than the header table empties it, and is not added
+        let inserted = match (&self.representation, &field) {
+            (&IndexedNameLiteral(IndexedLiteral { indexing: true, .. }), &Some(ref hf)) |
+            (&NewNameLiteral(NamedLiteral { indexing: true, .. }), &Some(ref hf)) if hf.size() <= table.max_size() => {
+                Some(hf.clone())
+            },
+            _ => None,
+        };
+
+        let kept = table.len() - if inserted.is_some() { 1 } else { 0 };
+        let evicted = entries.slice_from(kept).iter().rev().map(|hf| hf.clone()).collect();
+
+        Ok(Resolved {
+            field: field,
+            referenced: referenced,
+            inserted: inserted,
+            evicted: evicted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod disassembler_test {
+    use serialize::hex::FromHex;
+
+    use decoder::{Decoder, InvalidIndex, TruncatedString};
+    use header_field::HeaderField;
+    use representation::{IndexedHeader, IndexedLiteral};
+    use disassembler::{disassemble, resolve, IndexedHeaderField, IndexedNameLiteral};
+
+    #[test]
+    fn disassemble_test() {
+        // RFC 7541 C.3.1
+        let block = "828684410f7777772e6578616d706c652e636f6d".from_hex().unwrap();
+        let (representations, error) = disassemble(block.as_slice());
+
+        assert!(error.is_none());
+        assert!(representations.len() == 4);
+        assert!(representations[0].representation == IndexedHeaderField(IndexedHeader::new(2)));
+        assert!(representations[2].start == 2 && representations[2].end == 3);
+        assert!(representations[3].start == 3 && representations[3].end == 20);
+        assert!(representations[3].representation ==
+                IndexedNameLiteral(IndexedLiteral::new(true, false, 1, false, (~"www.example.com").into_bytes())));
+
+        let mut decoder = Decoder::new();
+        let resolved = resolve(block.as_slice(), representations.as_slice(), &mut decoder).unwrap();
+
+        assert!(resolved[0].referenced == Some((2, HeaderField::new(~":method", ~"GET"))));
+        assert!(resolved[0].inserted.is_none());
+        assert!(resolved[3].referenced == Some((1, HeaderField::new(~":authority", ~""))));
+        assert!(resolved[3].field == Some(HeaderField::new(~":authority", ~"www.example.com")));
+        assert!(resolved[3].inserted == resolved[3].field);
+        assert!(resolved[3].evicted.len() == 0);
+    }
+
+    #[test]
+    fn eviction_test() {
+        // RFC 7541 C.5.1 and C.5.2, with a header table of 256 octets
+        let mut decoder = Decoder::with_max_table_size(256);
+
+        let block = "4803333032580770726976617465611d4d6f6e2c203231204f637420323031332032303a31333a323120474d546e1768747470733a2f2f7777772e6578616d706c652e636f6d".from_hex().unwrap();
+        let (representations, _) = disassemble(block.as_slice());
+        resolve(block.as_slice(), representations.as_slice(), &mut decoder).unwrap();
+
+        let block = "4803333037c1c0bf".from_hex().unwrap();
+        let (representations, _) = disassemble(block.as_slice());
+        let resolved = resolve(block.as_slice(), representations.as_slice(), &mut decoder).unwrap();
+
+        // :status 307 evicts :status 302
+        assert!(resolved[0].inserted == Some(HeaderField::new(~":status", ~"307")));
+        assert!(resolved[0].evicted == ~[HeaderField::new(~":status", ~"302")]);
+        assert!(resolved[1].referenced == Some((65, HeaderField::new(~"cache-control", ~"private"))));
+    }
+
+    #[test]
+    fn rejected_block_test() {
+        // A string literal runs past the end of the header block,
+        // and the representation before it is parsed nonetheless
+        let (representations, error) = disassemble(&[0x82, 0x40, 0x03, 0x61]);
+        assert!(representations.len() == 1);
+        assert!(representations[0].representation == IndexedHeaderField(IndexedHeader::new(2)));
+        match error {
+            Some(TruncatedString(2)) => {},
+            _ => fail!("The truncated string literal was not detected"),
+        }
+
+        // The index is parsed, but refers to an empty header table
+        let block: &[u8] = &[0x82, 0xC0];
+        let (representations, error) = disassemble(block);
+        assert!(representations.len() == 2 && error.is_none());
+
+        let mut decoder = Decoder::new();
+        assert!(representations[0].resolve(block, &mut decoder).is_ok());
+        match representations[1].resolve(block, &mut decoder) {
+            Err(InvalidIndex(64, 1)) => {},
+            _ => fail!("The invalid index was not detected"),
+        }
+    }
+}
@@ -1,20 +1,48 @@
-// TODO: Being able to resize the header table (API feature)
+use std::cmp;
+use collections::{HashSet, HashMap};
 
-use collections::HashSet;
-
-use header_table::{HeaderTable, DEFAULT_HEADER_TABLE_SIZE};
-use reference_set::ReferenceSet;
+use header_table::{HeaderTable, HeaderTableView, DEFAULT_HEADER_TABLE_SIZE};
 use header_field::HeaderField;
 use static_header_table::StaticHeaderTable;
 use representation::{IndexedHeader, IndexedLiteral, NamedLiteral, ContextUpdate, Representation};
-use huffman::huffman_encoder::HuffmanEncoder;
+use huffman::huffman_encoder::{HuffmanEncoder, encoded_len};
+use indexing_policy::{IndexingPolicy, DefaultPolicy, Indexing, Indexed, Incremental, NeverIndexed};
+use indexing_policy::{NoMatch, NameMatch, FullMatch, restore_policy};
+use snapshot::{EncoderState, SnapshotError, ContextPoisoned, UnknownPolicy, check_entries};
+
+/// The reason a header block could not be encoded.
+/// Except for `Poisoned` and `HeaderListTooLarge`, every variant is a violation of an
+/// internal invariant of the encoding context. After such an error the context is poisoned.
+#[deriving(Eq, Show, Clone)]
+pub enum EncoderError {
+    /// A table lookup produced an index (the value) outside the static and the header table.
+    IndexOutOfBounds(uint),
+    /// The header table could not be shrunk to its maximum size.
+    EvictionFailed,
+    /// An earlier call to `encode` failed. The encoding context must be discarded.
+    Poisoned,
+    /// The size of the header list (the value) exceeds the peer's SETTINGS_MAX_HEADER_LIST_SIZE.
+    /// Nothing has been encoded, and the encoding context can still be used.
+    HeaderListTooLarge(uint),
+}
 
 /// An implementation of an HPACK encoding context for HTTP/2.
 pub struct Encoder {
     priv header_table:        ~HeaderTable,
-    priv reference_set:       ~ReferenceSet,
     priv static_header_table: ~StaticHeaderTable,
     priv huffman_encoder:     HuffmanEncoder,
+    priv poisoned:            bool,
+    // (Smallest, Final) header table size since the last header block
+    priv pending_size_update: Option<(uint, uint)>,
+    // Insertion numbers of the most recent header field with a given name,
+    // and of the most recent copy of a given header field in the header table
+    priv name_index:          HashMap<~[u8], uint>,
+    priv field_index:         HashMap<HeaderField, uint>,
+    // Names of header fields that are always encoded as literals never indexed
+    priv sensitive_names:     HashSet<~[u8]>,
+    priv policy:              ~IndexingPolicy,
+    // The peer's SETTINGS_MAX_HEADER_LIST_SIZE, if any
+    priv max_header_list_size: Option<uint>,
 }
 
 impl Encoder {
@@ -22,147 +50,658 @@ impl Encoder {
     pub fn new() -> Encoder {
         Encoder {
             header_table:        ~HeaderTable::new(DEFAULT_HEADER_TABLE_SIZE),
-            reference_set:       ~ReferenceSet::new(),
             static_header_table: ~StaticHeaderTable::new(),
             huffman_encoder:     HuffmanEncoder::new(),
+            poisoned:            false,
+            pending_size_update: None,
+            name_index:          HashMap::new(),
+            field_index:         HashMap::new(),
+            sensitive_names:     HashSet::new(),
+            policy:              ~DefaultPolicy as ~IndexingPolicy,
+            max_header_list_size: None,
+        }
+    }
+
+    /// Change how header fields are represented from the next header block on.
+    /// See `indexing_policy` for the built-in policies.
+    pub fn set_indexing_policy(&mut self, policy: ~IndexingPolicy) {
+        self.policy = policy;
+    }
+
+    /// Create an empty encoding context with a header table size other than
+    /// the default of 4096 octets, which the peer is known to use already.
+    /// Unlike `set_max_table_size` the size is not signalled to the peer.
+    pub fn with_max_table_size(max_size: uint) -> Encoder {
+        let mut encoder = Encoder::new();
+        encoder.header_table.set_max_size(max_size);
+
+        encoder
+    }
+
+    /// The current maximum size of the header table.
+    pub fn get_max_table_size(&self) -> uint {
+        self.header_table.get_max_size()
+    }
+
+    /// Treat every header field with the given name as sensitive, e.g. `authorization` or `cookie`.
+    /// Sensitive header fields are encoded as literals never indexed, so their values are
+    /// never added to a header table, where they could be probed for by an attacker.
+    pub fn add_sensitive_name(&mut self, name: ~str) {
+        self.sensitive_names.insert(name.into_bytes());
+    }
+
+    /// Change the maximum size of the header table.
+    /// The header table is shrunk right away, and the change is signalled to the peer
+    /// with a Dynamic Table Size Update at the start of the next header block.
+    /// The size must not exceed the peer's SETTINGS_HEADER_TABLE_SIZE.
+    pub fn set_max_table_size(&mut self, max_size: uint) -> Result<(), EncoderError> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
+
+        // "[...] the smallest maximum table size that occurs in that interval
+        // MUST be signaled in a dynamic table size update.  The final maximum
+        // size is always signaled, resulting in at most two dynamic table
+        // size updates."
+        self.pending_size_update = match self.pending_size_update {
+            Some((smallest, _)) => Some((cmp::min(smallest, max_size), max_size)),
+            None                => Some((max_size, max_size)),
+        };
+
+        self.header_table.set_max_size(max_size);
+
+        match self.evict() {
+            Ok(()) => Ok(()),
+            Err(e) => {
+                self.poisoned = true;
+                Err(e)
+            }
+        }
+    }
+
+    /// Set the maximum size of a header list, i.e. the peer's SETTINGS_MAX_HEADER_LIST_SIZE.
+    /// `encode` refuses larger header lists. By default the size is unlimited.
+    pub fn set_max_header_list_size(&mut self, max_size: uint) {
+        self.max_header_list_size = Some(max_size);
+    }
+
+    /// Check whether a set of header fields is within the peer's maximum header list size,
+    /// without encoding it. The size of a header list is the sum of `HeaderField::size`.
+    pub fn check_header_list_size(&self, fields: &HashSet<HeaderField>) -> Result<(), EncoderError> {
+        self.check_list_size(fields.iter())
+    }
+
+    // The size check of check_header_list_size, for header lists in any order
+    fn check_list_size<'a, I: Iterator<&'a HeaderField>>(&self, mut fields: I) -> Result<(), EncoderError> {
+        let size = fields.fold(0, |size, hf| size + hf.size());
+
+        match self.max_header_list_size {
+            Some(max_size) if size > max_size => Err(HeaderListTooLarge(size)),
+            _ => Ok(())
         }
     }
 
     /// Return a headerblock of encoding a given set of header fields with the current context.
-    pub fn encode(&mut self, fields: ~HashSet<HeaderField>) -> ~[u8] {
-        let mut ref_set: HashSet<HeaderField> = HashSet::new();
-        let mut header_block: ~[u8] = ~[];
+    /// Return an `EncoderError` if the encoding context is in an inconsistent state.
+    /// Once an error has been returned, every later call returns `Poisoned`,
+    /// since the peer's decoding context can no longer be assumed to match ours.
+    pub fn encode(&mut self, fields: ~HashSet<HeaderField>) -> Result<~[u8], EncoderError> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
+
+        propagate_err!(self.check_header_list_size(&*fields));
 
-        for (hf, _) in self.reference_set.references.iter() {
-            ref_set.insert(hf.clone());
+        match self.encode_fields(fields.iter()) {
+            Ok(header_block) => Ok(header_block),
+            Err(e) => {
+                self.poisoned = true;
+                Err(e)
+            }
         }
+    }
 
-        let mut to_add = fields.difference(&ref_set);  // Creates an iterator containing the difference
-        let mut to_remove = ref_set.difference(fields);
+    /// Like `encode`, but the header fields are encoded in the order given,
+    /// so the header block is the same every time for the same context and header list.
+    pub fn encode_list(&mut self, fields: &[HeaderField]) -> Result<~[u8], EncoderError> {
+        if self.poisoned {
+            return Err(Poisoned);
+        }
 
-        // If the number of fields to remove is larger than half the length
-        // of the reference_set => Empty the reference set
-        // TODO: Make it a setting instead of statically use 50%
-        if to_remove.len() > self.reference_set.len() / 2 {
-            // Create an context update of [0011 0000]
-            let ref_set_empty = ContextUpdate::new(true, 0);
-            header_block = ref_set_empty.encode();
-        } else {
-            // Create indexed headers to all the references we want removed
-            // from the reference_set
-            for hf in to_remove {
-                match self.header_table.find(hf.clone()) {
-                    Some((index, true)) => {
-                        let indexed_header = IndexedHeader::new(index);
-                        header_block.push_all_move(indexed_header.encode());
-
-                        self.reference_set.remove(hf);
-                    },
-                    Some((_, false)) => { fail!("Tried to remove a header field with a different value.") },
-                    None => { fail!("Tried to remove header field not present in the header nor the static header table.") } 
-                }
+        propagate_err!(self.check_list_size(fields.iter()));
+
+        match self.encode_fields(fields.iter()) {
+            Ok(header_block) => Ok(header_block),
+            Err(e) => {
+                self.poisoned = true;
+                Err(e)
             }
         }
+    }
+
+    /// A read-only view of the header table, e.g. to inspect its entries and size.
+    pub fn header_table<'a>(&'a self) -> HeaderTableView<'a> {
+        self.header_table.view()
+    }
+
+    /// Take a snapshot of the encoding context, from which `restore` builds an identical one.
+    /// A poisoned encoder cannot be saved.
+    pub fn snapshot(&self) -> Result<EncoderState, SnapshotError> {
+        if self.poisoned {
+            return Err(ContextPoisoned);
+        }
+
+        // Sorted, so the same context always gives the same snapshot
+        let mut sensitive_names: ~[~[u8]] = self.sensitive_names.iter().map(|name| name.clone()).collect();
+        sensitive_names.sort();
+
+        Ok(EncoderState {
+            max_table_size: self.header_table.get_max_size(),
+            entries: self.header_table.iter().map(|hf| hf.clone()).collect(),
+            max_header_list_size: self.max_header_list_size,
+            pending_size_update: self.pending_size_update,
+            sensitive_names: sensitive_names,
+            policy: self.policy.snapshot(),
+        })
+    }
+
+    /// Build an encoding context from a snapshot.
+    /// The indexing policy is restored with `indexing_policy::restore_policy`.
+    pub fn restore(state: &EncoderState) -> Result<Encoder, SnapshotError> {
+        let policy = match state.policy {
+            Some((ref name, ref policy_state)) => match restore_policy(name.as_slice(), policy_state) {
+                Some(policy) => policy,
+                None         => return Err(UnknownPolicy(name.clone())),
+            },
+            None => return Err(UnknownPolicy(~"")),
+        };
+
+        Encoder::restore_with_policy(state, policy)
+    }
+
+    /// Build an encoding context from a snapshot, with an indexing policy that cannot be
+    /// restored from it, i.e. one whose `IndexingPolicy::snapshot` returns None.
+    pub fn restore_with_policy(state: &EncoderState, policy: ~IndexingPolicy) -> Result<Encoder, SnapshotError> {
+        propagate_err!(check_entries(state.entries.as_slice(), state.max_table_size));
+
+        let mut encoder = Encoder::with_max_table_size(state.max_table_size);
 
-        for hf in to_add {
-                match self.find_header(hf.clone()) {
-                    // (Index, PerfectMatch)
-                    Some((index, true)) => {
-                        let indexed_header = IndexedHeader::new(index);
-                        header_block.push_all_move(indexed_header.encode());
-
-                        self.reference_set.add(hf.clone(), true);
-
-                        // If index is in the static header table => add to the header table
-                        if index > self.header_table.len() {
-                            self.header_table.add(hf.clone());
-                            self.evict();
-                        }
-                    },
-                    Some((index, false)) => {
-                        // Create an indexed literal without indexing.
-                        // We do this since the benefit from indexing the header field
-                        // might not be that big - the chance of the next request wanting to use
-                        // the same header value is little (this is an assumption)
-                        // Room for optimization: e.g. Index if the header key is "server"
-
-                        let mut value_bytes;
-
-                        let huffman_value = self.huffman_encoder.encode(hf.value.clone().into_bytes());
-                        let value_use_huffman = huffman_value.len() < hf.value.len();
-
-                        if value_use_huffman {
-                            value_bytes = huffman_value;
-                        } else {
-                            value_bytes = hf.value.clone().into_bytes();
-                        }
-
-                        let indexed_literal = IndexedLiteral::new(false, false, index, value_use_huffman, value_bytes);
-                        header_block.push_all_move(indexed_literal.encode());
-                    },
-                    None => { 
-                        // Not in any of the tables. Send as Named Literal and add to header table and reference set
-
-                        let mut key_bytes;
-                        let mut value_bytes;
-
-                        let huffman_key = self.huffman_encoder.encode(hf.key.clone().into_bytes());
-                        let huffman_value = self.huffman_encoder.encode(hf.value.clone().into_bytes());
-                        let key_use_huffman = huffman_key.len() < hf.key.len();
-                        let value_use_huffman = huffman_value.len() < hf.value.len();
-
-                        if key_use_huffman {
-                            key_bytes = huffman_key;
-                        } else {
-                            key_bytes = hf.key.clone().into_bytes();
-                        }
-
-                        if value_use_huffman {
-                            value_bytes = huffman_value;
-                        } else {
-                            value_bytes = hf.value.clone().into_bytes();
-                        }
-
-                        let named_literal = NamedLiteral::new(true, false, key_use_huffman, key_bytes, value_use_huffman, value_bytes);
-                        header_block.push_all_move(named_literal.encode());
-
-                        self.header_table.add(hf.clone());
-                        self.reference_set.add(hf.clone(), true);
-
-                        self.evict();
-                    } 
+        // The oldest first, so the most recent copy of a name is found first again
+        for hf in state.entries.rev_iter() {
+            encoder.add(hf.clone());
+        }
+
+        for name in state.sensitive_names.iter() {
+            encoder.sensitive_names.insert(name.clone());
+        }
+
+        encoder.pending_size_update = state.pending_size_update;
+        encoder.max_header_list_size = state.max_header_list_size;
+        encoder.policy = policy;
+
+        Ok(encoder)
+    }
+
+    /// Whether an earlier call to `encode` failed.
+    pub fn is_poisoned(&self) -> bool {
+        self.poisoned
+    }
+
+    fn encode_fields<'a, I: Iterator<&'a HeaderField>>(&mut self, fields: I) -> Result<~[u8], EncoderError> {
+        let mut header_block: ~[u8] = ~[];
+
+        // A Dynamic Table Size Update must occur at the beginning of the header block
+        match self.pending_size_update.take() {
+            Some((smallest, last)) => {
+                if smallest < last {
+                    header_block.push_all_move(ContextUpdate::new(smallest).encode());
+                }
+                header_block.push_all_move(ContextUpdate::new(last).encode());
+            },
+            None => {}
+        }
+
+        for hf in fields {
+            let found = self.find_header(hf);
+
+            let indexing = if hf.sensitive || self.sensitive_names.contains(&hf.key) {
+                // Sensitive header fields are sent as literals never indexed.
+                // Only the name may be taken from a header table.
+                NeverIndexed
+            } else {
+                let lookup = match found {
+                    Some((_, true))  => FullMatch,
+                    Some((_, false)) => NameMatch,
+                    None             => NoMatch,
+                };
+
+                // A header field which is not found cannot be indexed yet.
+                // It is added to the header table, so that its next occurrence can be.
+                match self.policy.indexing(hf, lookup) {
+                    Indexed if lookup != FullMatch => Incremental,
+                    indexing                       => indexing,
+                }
+            };
+
+            match (indexing, found) {
+                // (Index, PerfectMatch)
+                (Indexed, Some((index, true))) => {
+                    propagate_err!(self.check_index(index));
+
+                    let indexed_header = IndexedHeader::new(index);
+                    header_block.push_all_move(indexed_header.encode());
+                },
+                _ => {
+                    let literal = propagate_err!(self.encode_literal(hf, indexing));
+                    header_block.push_all_move(literal);
                 }
             }
+        }
 
-        header_block
+        Ok(header_block)
     }
 
-    // Search for a header field in the header table and the static header table. 
-    // If not found, returns (0, false) - a valid index is > 0.
-    // If found, returns the index and wether or not the value did also match.
-    fn find_header(&self, hf: HeaderField) -> Option<(uint, bool)> {
-        match self.header_table.find(hf.clone()) {
-            Some(x) => return Some(x),
-            None    => {
-                match self.static_header_table.find(hf.clone()) {
-                    Some((i, p)) => return Some((i + self.header_table.len(), p)),
-                    None => return None
-                }
+    // Encode a header field as a literal, with an indexed name if the name is found.
+    // If it is to be indexed incrementally the header field is added to the header table.
+    fn encode_literal(&mut self, hf: &HeaderField, indexing: Indexing) -> Result<~[u8], EncoderError> {
+        let incremental = indexing == Incremental;
+        let never_indexed = indexing == NeverIndexed;
+
+        let (value_use_huffman, value_bytes) = self.encode_string(hf.value.as_slice());
+
+        let literal = match self.find_name(hf) {
+            Some(index) => {
+                propagate_err!(self.check_index(index));
+
+                IndexedLiteral::new(incremental, never_indexed, index, value_use_huffman, value_bytes).encode()
+            },
+            None => {
+                let (key_use_huffman, key_bytes) = self.encode_string(hf.key.as_slice());
+
+                NamedLiteral::new(incremental, never_indexed, key_use_huffman, key_bytes, value_use_huffman, value_bytes).encode()
             }
+        };
+
+        if incremental {
+            self.add(hf.clone());
+
+            propagate_err!(self.evict());
+        }
+
+        Ok(literal)
+    }
+
+    // Return whether to use Huffman encoding for a string literal, and its octets.
+    // The indexing policy decides, and the string is only encoded once.
+    fn encode_string(&mut self, string: &[u8]) -> (bool, ~[u8]) {
+        if self.policy.use_huffman(string, encoded_len(string)) {
+            (true, self.huffman_encoder.encode(string))
+        } else {
+            (false, string.to_owned())
+        }
+    }
+
+    // Check that an index found by find_header can be resolved by the peer
+    fn check_index(&self, index: uint) -> Result<(), EncoderError> {
+        if index < 1 || index > self.static_header_table.len() + self.header_table.len() {
+            return Err(IndexOutOfBounds(index));
+        }
+
+        Ok(())
+    }
+
+    // Search for a header field in the static header table and the header table.
+    // If not found, returns None - a valid index is > 0.
+    // If found, returns the index and wether or not the value did also match.
+    // A full match is preferred over a partial match, and the static header
+    // table is preferred over the header table. Of several partial matches
+    // in the header table, the most recent one is used.
+    fn find_header(&self, hf: &HeaderField) -> Option<(uint, bool)> {
+        let static_match = self.static_header_table.find(hf);
+
+        match static_match {
+            Some((_, true)) => return static_match,
+            _ => {}
+        }
+
+        // Header table indices follow directly after the static header table
+        match self.field_index.find(hf).and_then(|&i| self.header_table.index_of(i)) {
+            Some(index) => return Some((index + self.static_header_table.len(), true)),
+            None => {}
+        }
+
+        if static_match.is_some() {
+            return static_match;
+        }
+
+        match self.name_index.find(&hf.key).and_then(|&i| self.header_table.index_of(i)) {
+            Some(index) => Some((index + self.static_header_table.len(), false)),
+            None => None
+        }
+    }
+
+    // Search for a header field name only.
+    // The static header table is preferred over the header table.
+    fn find_name(&self, hf: &HeaderField) -> Option<uint> {
+        match self.static_header_table.find(hf) {
+            Some((index, _)) => return Some(index),
+            None => {}
+        }
+
+        match self.name_index.find(&hf.key).and_then(|&i| self.header_table.index_of(i)) {
+            Some(index) => Some(index + self.static_header_table.len()),
+            None => None
         }
     }
 
+    // Add a header field to the header table and index it for find_header
+    fn add(&mut self, field: HeaderField) {
+        let insertion = self.header_table.add(field.clone());
+
+        self.name_index.insert(field.key.clone(), insertion);
+        self.field_index.insert(field, insertion);
+    }
+
     // Evict header fields until the header table is within its allowed size.
-    // We remove the oldest header field (remember, we add from the front)
-    // and also remove it from the reference set.
-    fn evict(&mut self) {
+    // We remove the oldest header field (remember, we add from the front).
+    fn evict(&mut self) -> Result<(), EncoderError> {
         while self.header_table.size() > self.header_table.get_max_size() && self.header_table.len() > 0 {
-            let header_table_length = self.header_table.len();
-            // If this unwrap fails, something is rotten in Denmark
-            // I.e. We assume it is always within bounds to remove the last element 
-            let removed_header_field = self.header_table.remove(header_table_length).unwrap();
-            self.reference_set.remove(&removed_header_field);
+            let insertion = self.header_table.oldest();
+
+            // If this fails, something is rotten in Denmark
+            // I.e. We assume it is always within bounds to remove the last element
+            match self.header_table.remove_oldest() {
+                Some(field) => self.unindex(insertion, &*field),
+                None        => return Err(EvictionFailed),
+            }
         }
+
+        Ok(())
+    }
+
+    // Forget an evicted header field, unless a more recent copy
+    // of it (or of its name) is still in the header table
+    fn unindex(&mut self, insertion: uint, field: &HeaderField) {
+        if self.name_index.find(&field.key) == Some(&insertion) {
+            self.name_index.remove(&field.key);
+        }
+
+        if self.field_index.find(field) == Some(&insertion) {
+            self.field_index.remove(field);
+        }
+    }
+}
+
+#[cfg(test)]
+mod encode_test {
+    use collections::HashSet;
+
+    use encoder::{Encoder, Poisoned, HeaderListTooLarge};
+    use indexing_policy::{IndexingPolicy, Indexing, Indexed, WithoutIndexing, Lookup};
+    use indexing_policy::{AggressivePolicy, ValueLengthPolicy, StaticTablePolicy};
+    use decoder::Decoder;
+    use header_field::HeaderField;
+    use representation::{ContextUpdate, Representation};
+
+    #[test]
+    fn poisoned_test() {
+        let mut encoder = Encoder::new();
+
+        let mut hs0: HashSet<HeaderField> = HashSet::new();
+        hs0.insert(HeaderField::new(~"foo", ~"bar"));
+
+        assert!(encoder.encode(~hs0.clone()).is_ok());
+        assert!(!encoder.is_poisoned());
+
+        // Simulate an earlier failure
+        encoder.poisoned = true;
+
+        assert!(encoder.encode(~hs0) == Err(Poisoned));
+        assert!(encoder.is_poisoned());
+    }
+
+    #[test]
+    fn set_max_table_size_test() {
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+
+        let mut hs0: HashSet<HeaderField> = HashSet::new();
+        hs0.insert(HeaderField::new(~"foo", ~"bar"));
+
+        // foo: bar is added to the header table
+        let hb0 = encoder.encode(~hs0.clone()).unwrap();
+        decoder.decode(hb0).unwrap();
+        assert!(encoder.header_table.len() == 1);
+
+        // Shrinking evicts right away
+        encoder.set_max_table_size(0).unwrap();
+        assert!(encoder.header_table.len() == 0);
+
+        // A single change is signalled once, and only in the next header block
+        let hb1 = encoder.encode(~HashSet::new()).unwrap();
+        assert!(hb1 == ContextUpdate::new(0).encode());
+        decoder.decode(hb1).unwrap();
+
+        let hb2 = encoder.encode(~HashSet::new()).unwrap();
+        assert!(hb2.len() == 0);
+
+        // Several changes signal the smallest size followed by the final size
+        encoder.set_max_table_size(100).unwrap();
+        encoder.set_max_table_size(50).unwrap();
+        encoder.set_max_table_size(200).unwrap();
+
+        let hb3 = encoder.encode(~hs0.clone()).unwrap();
+        let mut expected = ContextUpdate::new(50).encode();
+        expected.push_all_move(ContextUpdate::new(200).encode());
+        assert!(hb3.slice(0, expected.len()) == expected.as_slice());
+
+        let hl3 = decoder.decode(hb3).unwrap();
+        assert!(hl3.get(bytes!("foo"))[0].as_slice() == bytes!("bar"));
+
+        // Growing again does not need the smallest size
+        encoder.set_max_table_size(4096).unwrap();
+        let hb4 = encoder.encode(~HashSet::new()).unwrap();
+        assert!(hb4 == ContextUpdate::new(4096).encode());
+    }
+
+    #[test]
+    fn find_header_test() {
+        let mut encoder = Encoder::new();
+        let static_len = encoder.static_header_table.len();
+
+        // Static full and partial matches
+        assert!(encoder.find_header(&HeaderField::new(~":method", ~"GET")) == Some((2, true)));
+        assert!(encoder.find_header(&HeaderField::new(~":method", ~"PUT")) == Some((2, false)));
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"bar")).is_none());
+
+        encoder.add(HeaderField::new(~"foo", ~"bar"));
+        encoder.add(HeaderField::new(~"foo", ~"baz"));
+        encoder.add(HeaderField::new(~":method", ~"PUT"));
+
+        // A dynamic full match is preferred over a static partial match
+        assert!(encoder.find_header(&HeaderField::new(~":method", ~"PUT")) == Some((static_len + 1, true)));
+        assert!(encoder.find_header(&HeaderField::new(~":method", ~"PATCH")) == Some((2, false)));
+
+        // Indices follow the insertions
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"bar")) == Some((static_len + 3, true)));
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"baz")) == Some((static_len + 2, true)));
+
+        // The most recent partial match is used
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"qux")) == Some((static_len + 2, false)));
+
+        // Evicted header fields are not found, but a more recent copy is
+        encoder.add(HeaderField::new(~"foo", ~"bar"));
+        encoder.header_table.set_max_size(HeaderField::new(~"foo", ~"bar").size());
+        encoder.evict().unwrap();
+
+        assert!(encoder.header_table.len() == 1);
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"bar")) == Some((static_len + 1, true)));
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"baz")) == Some((static_len + 1, false)));
+        assert!(encoder.find_header(&HeaderField::new(~":method", ~"PUT")) == Some((2, false)));
+
+        encoder.header_table.set_max_size(0);
+        encoder.evict().unwrap();
+
+        assert!(encoder.find_header(&HeaderField::new(~"foo", ~"bar")).is_none());
+        assert!(encoder.name_index.len() == 0 && encoder.field_index.len() == 0);
+    }
+
+    #[test]
+    fn sensitive_test() {
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+
+        encoder.add_sensitive_name(~"cookie");
+
+        let mut hs0: HashSet<HeaderField> = HashSet::new();
+        hs0.insert(HeaderField::new(~"cookie", ~"secret"));
+        hs0.insert(HeaderField::new_sensitive(~"password", ~"hunter2"));
+
+        let hb0 = encoder.encode(~hs0.clone()).unwrap();
+
+        // Neither header field is added to the header table
+        assert!(encoder.header_table.len() == 0);
+
+        let fields = decoder.decode_slice(hb0.as_slice()).unwrap();
+        assert!(fields.len() == 2);
+
+        for hf in fields.iter() {
+            assert!(hf.sensitive);
+            assert!(hf.key_str() == Some("cookie") || hf.key_str() == Some("password"));
+        }
+
+        // A decoded sensitive header field stays sensitive when re-encoded
+        let mut hs1: HashSet<HeaderField> = HashSet::new();
+        for hf in fields.iter() {
+            hs1.insert(hf.to_header_field());
+        }
+
+        let mut relay = Encoder::new();
+        let hb1 = relay.encode(~hs1).unwrap();
+        assert!(relay.header_table.len() == 0);
+        assert!(hb1[0] & 0xF0 == 0x10);
+
+        // The name is taken from the static header table
+        let mut hs2: HashSet<HeaderField> = HashSet::new();
+        hs2.insert(HeaderField::new(~"cookie", ~"secret"));
+
+        let hb2 = encoder.encode(~hs2).unwrap();
+        assert!(hb2[0] == 0x1F && hb2[1] == 32 - 15); // cookie has index 32
+    }
+
+    #[test]
+    fn max_header_list_size_test() {
+        let mut encoder = Encoder::new();
+
+        let mut hs0: HashSet<HeaderField> = HashSet::new();
+        hs0.insert(HeaderField::new(~"foo", ~"bar"));
+        hs0.insert(HeaderField::new(~":method", ~"GET"));
+
+        // 38 + 42 octets
+        encoder.set_max_header_list_size(80);
+        assert!(encoder.check_header_list_size(&hs0).is_ok());
+
+        encoder.set_max_header_list_size(79);
+        assert!(encoder.check_header_list_size(&hs0) == Err(HeaderListTooLarge(80)));
+
+        // Nothing is encoded, and the encoder is not poisoned
+        assert!(encoder.encode(~hs0.clone()) == Err(HeaderListTooLarge(80)));
+        assert!(!encoder.is_poisoned());
+        assert!(encoder.header_table.len() == 0);
+
+        encoder.set_max_header_list_size(1000);
+        assert!(encoder.encode(~hs0).is_ok());
+    }
+
+    #[test]
+    fn indexing_policy_test() {
+        let mut hs0: HashSet<HeaderField> = HashSet::new();
+        hs0.insert(HeaderField::new(~":method", ~"GET"));
+        hs0.insert(HeaderField::new(~":path", ~"/some/path"));
+        hs0.insert(HeaderField::new(~"foo", ~"a long value of more than 16 octets"));
+
+        // By default only the new name is indexed
+        let mut encoder = Encoder::new();
+        encoder.encode(~hs0.clone()).unwrap();
+        assert!(encoder.header_table.len() == 1);
+
+        // Every header field not found is indexed
+        let mut encoder = Encoder::new();
+        encoder.set_indexing_policy(~AggressivePolicy as ~IndexingPolicy);
+        encoder.encode(~hs0.clone()).unwrap();
+        assert!(encoder.header_table.len() == 2);
+
+        // Long values are not indexed
+        let mut encoder = Encoder::new();
+        encoder.set_indexing_policy(~ValueLengthPolicy::new(16) as ~IndexingPolicy);
+        encoder.encode(~hs0.clone()).unwrap();
+        assert!(encoder.header_table.len() == 1);
+        assert!(encoder.header_table.get(1).unwrap().key_str() == Some(":path"));
+
+        // The header table is never used, and the header block still decodes
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+        encoder.set_indexing_policy(~StaticTablePolicy as ~IndexingPolicy);
+        let hb0 = encoder.encode(~hs0.clone()).unwrap();
+        assert!(encoder.header_table.len() == 0);
+
+        let hl0 = decoder.decode(hb0).unwrap();
+        assert!(hl0.get(bytes!(":path"))[0].as_slice() == bytes!("/some/path"));
+        assert!(hl0.get(bytes!(":method"))[0].as_slice() == bytes!("GET"));
+    }
+
+    struct NoHuffmanPolicy;
+
+    impl IndexingPolicy for NoHuffmanPolicy {
+        fn indexing(&mut self, _: &HeaderField, _: Lookup) -> Indexing {
+            WithoutIndexing
+        }
+
+        fn use_huffman(&mut self, _: &[u8], _: uint) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn custom_indexing_policy_test() {
+        let mut encoder = Encoder::new();
+        encoder.set_indexing_policy(~NoHuffmanPolicy as ~IndexingPolicy);
+
+        let mut hs0: HashSet<HeaderField> = HashSet::new();
+        hs0.insert(HeaderField::new(~":method", ~"GET"));
+
+        // Even a full match is sent as a literal: | 0 | 0 | 0 | 0 | 2 |, then "GET" as is
+        let hb0 = encoder.encode(~hs0).unwrap();
+        assert!(hb0 == ~[0x02, 3, 71, 69, 84]);
+        assert!(encoder.header_table.len() == 0);
+    }
+
+    struct AlwaysIndexedPolicy;
+
+    impl IndexingPolicy for AlwaysIndexedPolicy {
+        fn indexing(&mut self, _: &HeaderField, _: Lookup) -> Indexing {
+            Indexed
+        }
+
+        fn use_huffman(&mut self, _: &[u8], _: uint) -> bool {
+            false
+        }
+    }
+
+    #[test]
+    fn indexed_without_full_match_test() {
+        let mut encoder = Encoder::new();
+        encoder.set_indexing_policy(~AlwaysIndexedPolicy as ~IndexingPolicy);
+
+        // Without a match, and with a matching name (:method has index 2),
+        // Indexed falls back to a literal with incremental indexing
+        let hb0 = encoder.encode_list(&[HeaderField::new(~"foo", ~"bar"), HeaderField::new(~":method", ~"PUT")]).unwrap();
+        assert!(hb0 == ~[0x40, 3, 102, 111, 111, 3, 98, 97, 114, 0x42, 3, 80, 85, 84]);
+        assert!(encoder.header_table.len() == 2);
+
+        // From then on both are full matches
+        let hb1 = encoder.encode_list(&[HeaderField::new(~"foo", ~"bar"), HeaderField::new(~":method", ~"PUT")]).unwrap();
+        assert!(hb1 == ~[0x80 | 63, 0x80 | 62]);
     }
 }
 
@@ -0,0 +1,688 @@
+// Carrying header blocks in HTTP/2 frames. Comments enclosed in quotes are
+// citations from RFC 7540: http://tools.ietf.org/html/rfc7540
+//
+// A header block is sent in a HEADERS or PUSH_PROMISE frame, followed by as many
+// CONTINUATION frames as needed to stay within SETTINGS_MAX_FRAME_SIZE.
+// `HeaderFrames` splits the output of the `Encoder` into such frames, and a
+// `Reassembler` passes the header block to the `Decoder` frame by frame.
+//
+// Every frame starts with a 9-octet header:
+// +-----------------------------------------------+
+// |                 Length (24)                   |
+// +---------------+---------------+---------------+
+// |   Type (8)    |   Flags (8)   |
+// +-+-------------+---------------+-------------------------------+
+// |R|                 Stream Identifier (31)                      |
+// +=+=============================================================+
+// |                   Frame Payload (0...)                      ...
+// +---------------------------------------------------------------+
+use decoder::{Decoder, DecoderError};
+use header_field::HeaderField;
+
+// The frame types which carry header blocks
+pub static HEADERS: u8 = 0x1;
+pub static PUSH_PROMISE: u8 = 0x5;
+pub static CONTINUATION: u8 = 0x9;
+
+// The flags of HEADERS, PUSH_PROMISE and CONTINUATION frames
+pub static END_STREAM: u8 = 0x1;
+pub static END_HEADERS: u8 = 0x4;
+pub static PADDED: u8 = 0x8;
+pub static PRIORITY: u8 = 0x20;
+
+/// The size of the frame header.
+pub static FRAME_HEADER_SIZE: uint = 9;
+
+/// The initial value of SETTINGS_MAX_FRAME_SIZE, which is also its smallest allowed value.
+pub static DEFAULT_MAX_FRAME_SIZE: uint = 16384;
+
+/// The largest allowed value of SETTINGS_MAX_FRAME_SIZE.
+pub static MAX_MAX_FRAME_SIZE: uint = 16777215;
+
+/// The default maximum size of a header block received in a `Reassembler`,
+/// i.e. of the header block fragments of all its frames together.
+pub static DEFAULT_MAX_HEADER_BLOCK_SIZE: uint = 65536;
+
+static MAX_STREAM_ID: u32 = 0x7FFFFFFF;
+
+/// The reason header frames could not be written or received.
+/// Every variant but the last three is a connection error of type PROTOCOL_ERROR,
+/// or FRAME_SIZE_ERROR for `FrameTooLarge`.
+#[deriving(Eq, Show, Clone)]
+pub enum FrameError {
+    /// A stream identifier (the value) is 0 or does not fit into 31 bits.
+    InvalidStreamId(u32),
+    /// A maximum frame size (the value) outside 16384 to 16777215.
+    InvalidMaxFrameSize(uint),
+    /// The octets (the number) are not a whole frame, i.e. a frame header
+    /// followed by as many octets as its length says.
+    InvalidFrameLength(uint),
+    /// The length of a frame (the value) exceeds the maximum frame size.
+    FrameTooLarge(uint),
+    /// A frame of the type (the value) where a HEADERS or PUSH_PROMISE frame was expected,
+    /// or where a CONTINUATION frame was expected.
+    UnexpectedFrame(u8),
+    /// A CONTINUATION frame of another stream (second value) than
+    /// the header block in progress (first value).
+    InterleavedStream(u32, u32),
+    /// The payload of a frame (the length) is too short for the padding,
+    /// priority or promised stream fields its flags announce.
+    PayloadTooShort(uint),
+    /// The padding (the length) is as long as the frame payload or longer.
+    InvalidPadding(uint),
+    /// The header block was rejected by the decoder, a connection error of type COMPRESSION_ERROR.
+    CompressionFailed(DecoderError),
+    /// The header block received so far (the length) exceeds the maximum header block size.
+    /// The rest of it is not decoded, so the decoding context is lost: a connection error,
+    /// e.g. of type ENHANCE_YOUR_CALM.
+    HeaderBlockTooLarge(uint),
+    /// The header list of the stream (the value) exceeds the maximum header list size of the decoder.
+    /// The header block has been decoded completely, so this is only a stream error,
+    /// or a reason to respond with 431 (Request Header Fields Too Large).
+    HeaderListTooLarge(u32),
+}
+
+/// The priority fields of a HEADERS frame.
+#[deriving(Eq, Show, Clone)]
+pub struct Priority {
+    exclusive: bool,
+    /// The stream this stream depends on.
+    dependency: u32,
+    /// The weight minus one, as on the wire: 0 is a weight of 1, 255 a weight of 256.
+    weight: u8,
+}
+
+impl Priority {
+    pub fn new(exclusive: bool, dependency: u32, weight: u8) -> Priority {
+        Priority {
+            exclusive: exclusive,
+            dependency: dependency,
+            weight: weight
+        }
+    }
+}
+
+/// A received header block with the fields of its HEADERS or PUSH_PROMISE frame.
+#[deriving(Eq, Show, Clone)]
+pub struct HeaderBlock {
+    stream_id: u32,
+    /// The stream reserved by a PUSH_PROMISE frame. None for a HEADERS frame.
+    promised_stream_id: Option<u32>,
+    end_stream: bool,
+    priority: Option<Priority>,
+    /// The decoded header list, in order.
+    fields: ~[HeaderField],
+}
+
+/// Splits header blocks of one stream into a HEADERS or PUSH_PROMISE frame
+/// followed by CONTINUATION frames.
+pub struct HeaderFrames {
+    priv stream_id:          u32,
+    priv promised_stream_id: Option<u32>,
+    priv end_stream:         bool,
+    priv priority:           Option<Priority>,
+    priv pad_length:         Option<u8>,
+    priv max_frame_size:     uint,
+}
+
+impl HeaderFrames {
+    /// Header frames starting with a HEADERS frame.
+    pub fn headers(stream_id: u32) -> HeaderFrames {
+        HeaderFrames {
+            stream_id:          stream_id,
+            promised_stream_id: None,
+            end_stream:         false,
+            priority:           None,
+            pad_length:         None,
+            max_frame_size:     DEFAULT_MAX_FRAME_SIZE,
+        }
+    }
+
+    /// Header frames starting with a PUSH_PROMISE frame, which reserves `promised_stream_id`.
+    pub fn push_promise(stream_id: u32, promised_stream_id: u32) -> HeaderFrames {
+        let mut frames = HeaderFrames::headers(stream_id);
+        frames.promised_stream_id = Some(promised_stream_id);
+
+        frames
+    }
+
+    /// Set END_STREAM on the HEADERS frame, i.e. no DATA frames follow.
+    /// A PUSH_PROMISE frame has no such flag, and ignores it.
+    pub fn set_end_stream(&mut self, end_stream: bool) {
+        self.end_stream = end_stream;
+    }
+
+    /// Send priority fields in the HEADERS frame.
+    /// A PUSH_PROMISE frame has no such fields, and ignores them.
+    pub fn set_priority(&mut self, priority: Priority) {
+        self.priority = Some(priority);
+    }
+
+    /// Pad the first frame with `pad_length` octets, e.g. to obscure the size of the header block.
+    pub fn set_padding(&mut self, pad_length: u8) {
+        self.pad_length = Some(pad_length);
+    }
+
+    /// Set the maximum frame payload size, i.e. the peer's SETTINGS_MAX_FRAME_SIZE.
+    pub fn set_max_frame_size(&mut self, max_size: uint) -> Result<(), FrameError> {
+        propagate_err!(check_max_frame_size(max_size));
+        self.max_frame_size = max_size;
+
+        Ok(())
+    }
+
+    /// Split a header block into frames, each with its frame header. The last frame has END_HEADERS set.
+    /// "Header blocks MUST be transmitted as a contiguous sequence of frames,
+    /// with no interleaved frames of any other type or from any other stream."
+    pub fn split(&self, header_block: &[u8]) -> Result<~[~[u8]], FrameError> {
+        propagate_err!(check_stream_id(self.stream_id));
+
+        let mut prefix = ~[];
+        let mut flags = 0;
+
+        match self.pad_length {
+            Some(pad_length) => {
+                prefix.push(pad_length);
+                flags |= PADDED;
+            },
+            None => {}
+        }
+
+        let kind = match self.promised_stream_id {
+            Some(promised_stream_id) => {
+                propagate_err!(check_stream_id(promised_stream_id));
+                prefix.push_all_move(encode_stream_id(promised_stream_id, false));
+
+                PUSH_PROMISE
+            },
+            None => {
+                match self.priority {
+                    Some(ref priority) => {
+                        if priority.dependency > MAX_STREAM_ID {
+                            return Err(InvalidStreamId(priority.dependency));
+                        }
+                        prefix.push_all_move(encode_stream_id(priority.dependency, priority.exclusive));
+                        prefix.push(priority.weight);
+                        flags |= PRIORITY;
+                    },
+                    None => {}
+                }
+
+                if self.end_stream {
+                    flags |= END_STREAM;
+                }
+
+                HEADERS
+            }
+        };
+
+        // At most 1 + 5 + 255 octets, which always leaves room in the smallest frame
+        let padding = self.pad_length.unwrap_or(0) as uint;
+        let first_size = self.max_frame_size - prefix.len() - padding;
+
+        let mut frames = ~[];
+        let mut rest = header_block;
+
+        // The first frame is sent even for an empty header block
+        loop {
+            let size = if frames.is_empty() { first_size } else { self.max_frame_size };
+            let fragment = rest.slice_to(if rest.len() < size { rest.len() } else { size });
+            rest = rest.slice_from(fragment.len());
+
+            let mut frame = ~[];
+            if frames.is_empty() {
+                let flags = if rest.is_empty() { flags | END_HEADERS } else { flags };
+                frame.push_all_move(encode_frame_header(prefix.len() + fragment.len() + padding, kind, flags, self.stream_id));
+                frame.push_all(prefix.as_slice());
+                frame.push_all(fragment);
+                frame.grow(padding, &0u8);
+            } else {
+                let flags = if rest.is_empty() { END_HEADERS } else { 0 };
+                frame.push_all_move(encode_frame_header(fragment.len(), CONTINUATION, flags, self.stream_id));
+                frame.push_all(fragment);
+            }
+            frames.push(frame);
+
+            if rest.is_empty() {
+                return Ok(frames);
+            }
+        }
+    }
+}
+
+// The fields of the first frame of the header block in progress
+struct Pending {
+    stream_id:          u32,
+    promised_stream_id: Option<u32>,
+    end_stream:         bool,
+    priority:           Option<Priority>,
+    received:           uint,            // The octets of the header block so far
+    fields:             ~[HeaderField],  // The header fields decoded so far
+}
+
+/// Joins the HEADERS or PUSH_PROMISE frame and the CONTINUATION frames of a header block.
+/// The header block fragment of every frame is decoded as soon as it arrives, so only the
+/// decoded header fields are kept, which the maximum header list size of the decoder limits.
+/// While `is_in_progress` is true, every received frame must be passed to `receive`,
+/// since frames of other types and of other streams are not allowed in between.
+pub struct Reassembler {
+    priv pending:               Option<Pending>,
+    priv max_frame_size:        uint,
+    priv max_header_block_size: uint,
+}
+
+impl Reassembler {
+    pub fn new() -> Reassembler {
+        Reassembler {
+            pending:               None,
+            max_frame_size:        DEFAULT_MAX_FRAME_SIZE,
+            max_header_block_size: DEFAULT_MAX_HEADER_BLOCK_SIZE,
+        }
+    }
+
+    /// Set the maximum frame payload size, i.e. the SETTINGS_MAX_FRAME_SIZE we have advertised.
+    pub fn set_max_frame_size(&mut self, max_size: uint) -> Result<(), FrameError> {
+        propagate_err!(check_max_frame_size(max_size));
+        self.max_frame_size = max_size;
+
+        Ok(())
+    }
+
+    /// Set the maximum size of a header block, which bounds the CONTINUATION frames of one stream.
+    pub fn set_max_header_block_size(&mut self, max_size: uint) {
+        self.max_header_block_size = max_size;
+    }
+
+    /// Whether a header block has been started, and CONTINUATION frames are expected.
+    pub fn is_in_progress(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Receive a frame, including its frame header. Once the frame with END_HEADERS has arrived,
+    /// the decoded header block is returned, and until then None.
+    /// After an error the reassembler, and the decoder, start over with a new header block.
+    pub fn receive(&mut self, frame: &[u8], decoder: &mut Decoder) -> Result<Option<HeaderBlock>, FrameError> {
+        let result = self.receive_frame(frame, decoder);
+
+        // Unless the decoder has failed, which ends the header block, it has to be
+        // ended here, so the next header block is not taken for a continuation
+        match result {
+            Err(CompressionFailed(_)) => self.pending = None,
+            Err(_) => {
+                if self.pending.take().is_some() {
+                    let _ = decoder.finish();
+                }
+            },
+            Ok(_) => {}
+        }
+
+        result
+    }
+
+    fn receive_frame(&mut self, frame: &[u8], decoder: &mut Decoder) -> Result<Option<HeaderBlock>, FrameError> {
+        if frame.len() < FRAME_HEADER_SIZE {
+            return Err(InvalidFrameLength(frame.len()));
+        }
+
+        let length = (frame[0] as uint << 16) | (frame[1] as uint << 8) | frame[2] as uint;
+        let kind = frame[3];
+        let flags = frame[4];
+        let stream_id = decode_stream_id(frame.slice(5, 9));
+        let payload = frame.slice_from(FRAME_HEADER_SIZE);
+
+        if payload.len() != length {
+            return Err(InvalidFrameLength(frame.len()));
+        }
+
+        if length > self.max_frame_size {
+            return Err(FrameTooLarge(length));
+        }
+
+        // "HEADERS frames MUST be associated with a stream. If a HEADERS frame is received
+        // whose stream identifier field is 0x0, the recipient MUST respond with a connection
+        // error of type PROTOCOL_ERROR." Likewise for PUSH_PROMISE and CONTINUATION.
+        if stream_id == 0 {
+            return Err(InvalidStreamId(0));
+        }
+
+        let pending_stream_id = self.pending.as_ref().map(|pending| pending.stream_id);
+
+        let fragment = match pending_stream_id {
+            Some(pending_stream_id) => {
+                if kind != CONTINUATION {
+                    return Err(UnexpectedFrame(kind));
+                }
+                if stream_id != pending_stream_id {
+                    return Err(InterleavedStream(pending_stream_id, stream_id));
+                }
+
+                payload
+            },
+            None => {
+                // "A CONTINUATION frame MUST be preceded by a HEADERS, PUSH_PROMISE
+                // or CONTINUATION frame without the END_HEADERS flag set."
+                if kind != HEADERS && kind != PUSH_PROMISE {
+                    return Err(UnexpectedFrame(kind));
+                }
+
+                let (pending, fragment) = propagate_err!(parse_first_frame(kind, flags, stream_id, payload));
+                self.pending = Some(pending);
+
+                fragment
+            }
+        };
+
+        let max_header_block_size = self.max_header_block_size;
+
+        match self.pending {
+            Some(ref mut pending) => {
+                pending.received += fragment.len();
+                if pending.received > max_header_block_size {
+                    return Err(HeaderBlockTooLarge(pending.received));
+                }
+
+                match decoder.decode_fragment(fragment) {
+                    Ok(fields) => pending.fields.push_all_move(fields),
+                    Err(e)     => return Err(CompressionFailed(e)),
+                }
+            },
+            None => {}
+        }
+
+        if flags & END_HEADERS == 0 {
+            return Ok(None);
+        }
+
+        let pending = self.pending.take_unwrap();
+
+        match decoder.finish() {
+            Ok(())                                => {},
+            Err(::decoder::HeaderListTooLarge(_)) => return Err(HeaderListTooLarge(pending.stream_id)),
+            Err(e)                                => return Err(CompressionFailed(e)),
+        }
+
+        Ok(Some(HeaderBlock {
+            stream_id: pending.stream_id,
+            promised_stream_id: pending.promised_stream_id,
+            end_stream: pending.end_stream,
+            priority: pending.priority,
+            fields: pending.fields,
+        }))
+    }
+}
+
+// Parse the payload of a HEADERS or PUSH_PROMISE frame into its fields and its header block fragment
+fn parse_first_frame<'a>(kind: u8, flags: u8, stream_id: u32, payload: &'a [u8]) -> Result<(Pending, &'a [u8]), FrameError> {
+    let mut rest = payload;
+
+    let pad_length = if flags & PADDED != 0 {
+        if rest.is_empty() {
+            return Err(PayloadTooShort(payload.len()));
+        }
+        let pad_length = rest[0] as uint;
+        rest = rest.slice_from(1);
+
+        pad_length
+    } else {
+        0
+    };
+
+    let mut pending = Pending {
+        stream_id:          stream_id,
+        promised_stream_id: None,
+        end_stream:         false,
+        priority:           None,
+        received:           0,
+        fields:             ~[],
+    };
+
+    if kind == PUSH_PROMISE {
+        if rest.len() < 4 {
+            return Err(PayloadTooShort(payload.len()));
+        }
+
+        let promised_stream_id = decode_stream_id(rest.slice_to(4));
+        if promised_stream_id == 0 {
+            return Err(InvalidStreamId(0));
+        }
+        pending.promised_stream_id = Some(promised_stream_id);
+        rest = rest.slice_from(4);
+    } else {
+        pending.end_stream = flags & END_STREAM != 0;
+
+        if flags & PRIORITY != 0 {
+            if rest.len() < 5 {
+                return Err(PayloadTooShort(payload.len()));
+            }
+
+            pending.priority = Some(Priority {
+                exclusive: rest[0] & 0x80 != 0,
+                dependency: decode_stream_id(rest.slice_to(4)),
+                weight: rest[4],
+            });
+            rest = rest.slice_from(5);
+        }
+    }
+
+    // "If the length of the padding is the length of the frame payload or greater,
+    // the recipient MUST treat this as a connection error of type PROTOCOL_ERROR."
+    // The frame payload includes the Pad Length field, so the padding may take up
+    // all of the rest, leaving an empty header block fragment.
+    if pad_length > rest.len() {
+        return Err(InvalidPadding(pad_length));
+    }
+
+    Ok((pending, rest.slice_to(rest.len() - pad_length)))
+}
+
+fn check_stream_id(stream_id: u32) -> Result<(), FrameError> {
+    if stream_id == 0 || stream_id > MAX_STREAM_ID {
+        return Err(InvalidStreamId(stream_id));
+    }
+
+    Ok(())
+}
+
+fn check_max_frame_size(max_size: uint) -> Result<(), FrameError> {
+    if max_size < DEFAULT_MAX_FRAME_SIZE || max_size > MAX_MAX_FRAME_SIZE {
+        return Err(InvalidMaxFrameSize(max_size));
+    }
+
+    Ok(())
+}
+
+fn encode_frame_header(length: uint, kind: u8, flags: u8, stream_id: u32) -> ~[u8] {
+    let mut header = ~[(length >> 16) as u8, (length >> 8) as u8, length as u8, kind, flags];
+    header.push_all_move(encode_stream_id(stream_id, false));
+
+    header
+}
+
+// A 31-bit stream identifier, with the flag (E or R) in the most significant bit
+fn encode_stream_id(stream_id: u32, flag: bool) -> ~[u8] {
+    let first = (stream_id >> 24) as u8 | if flag { 0x80 } else { 0 };
+
+    ~[first, (stream_id >> 16) as u8, (stream_id >> 8) as u8, stream_id as u8]
+}
+
+// The 31-bit stream identifier of 4 octets, ignoring the most significant bit
+fn decode_stream_id(octets: &[u8]) -> u32 {
+    ((octets[0] & 0x7F) as u32 << 24) | (octets[1] as u32 << 16) | (octets[2] as u32 << 8) | octets[3] as u32
+}
+
+#[cfg(test)]
+mod frame_test {
+    use encoder::Encoder;
+    use decoder::{Decoder, InvalidIndex};
+    use test::fields;
+    use frame::{HeaderFrames, Reassembler, Priority, HEADERS, PUSH_PROMISE, CONTINUATION};
+    use frame::{END_STREAM, END_HEADERS, PADDED, PRIORITY};
+    use frame::{InvalidStreamId, InvalidMaxFrameSize, FrameTooLarge, UnexpectedFrame};
+    use frame::{InterleavedStream, InvalidPadding, CompressionFailed, HeaderBlockTooLarge, HeaderListTooLarge};
+
+    #[test]
+    fn split_test() {
+        let header_block = ~[0x82u8, ..40000];
+
+        let mut frames = HeaderFrames::headers(3);
+        frames.set_priority(Priority::new(true, 1, 15));
+        frames.set_padding(10);
+        let split = frames.split(header_block.as_slice()).unwrap();
+
+        // 1 + 5 octets of fields and 10 of padding leave 16368 octets of the header block
+        assert!(split.len() == 3);
+        assert!(split[0].slice_to(9) == &[0x00, 0x40, 0x00, HEADERS, PRIORITY | PADDED, 0, 0, 0, 3]);
+        assert!(split[0].slice(9, 15) == &[10, 0x80, 0, 0, 1, 15]);
+        assert!(split[0].slice_from(16384 + 9 - 10).iter().all(|&octet| octet == 0));
+        assert!(split[1].slice_to(9) == &[0x00, 0x40, 0x00, CONTINUATION, 0, 0, 0, 0, 3]);
+        assert!(split[2].len() == 9 + 40000 - 16368 - 16384);
+        assert!(split[2][4] == END_HEADERS);
+
+        // A small header block fits into a single frame
+        let mut frames = HeaderFrames::headers(1);
+        frames.set_end_stream(true);
+        let split = frames.split(&[0x82, 0x84]).unwrap();
+        assert!(split == ~[~[0, 0, 2, HEADERS, END_STREAM | END_HEADERS, 0, 0, 0, 1, 0x82, 0x84]]);
+
+        // So does an empty one
+        let split = HeaderFrames::push_promise(1, 2).split(&[]).unwrap();
+        assert!(split == ~[~[0, 0, 4, PUSH_PROMISE, END_HEADERS, 0, 0, 0, 1, 0, 0, 0, 2]]);
+
+        assert!(HeaderFrames::headers(0).split(&[]) == Err(InvalidStreamId(0)));
+        let mut frames = HeaderFrames::headers(1);
+        assert!(frames.set_max_frame_size(16383) == Err(InvalidMaxFrameSize(16383)));
+    }
+
+    #[test]
+    fn reassemble_test() {
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+        let mut reassembler = Reassembler::new();
+
+        // A value that needs CONTINUATION frames
+        let hs = fields(&[(":method", "GET"), (":path", "/"), ("cookie", "a".repeat(40000).as_slice())]);
+        let header_block = encoder.encode_list(hs.as_slice()).unwrap();
+
+        let mut frames = HeaderFrames::headers(5);
+        frames.set_end_stream(true);
+        frames.set_padding(3);
+        let split = frames.split(header_block.as_slice()).unwrap();
+        assert!(split.len() > 1);
+
+        for frame in split.init().iter() {
+            assert!(reassembler.receive(frame.as_slice(), &mut decoder).unwrap().is_none());
+            assert!(reassembler.is_in_progress());
+        }
+
+        let received = reassembler.receive(split.last().unwrap().as_slice(), &mut decoder).unwrap().unwrap();
+        assert!(!reassembler.is_in_progress());
+        assert!(received.stream_id == 5 && received.end_stream);
+        assert!(received.promised_stream_id.is_none() && received.priority.is_none());
+        assert!(received.fields == hs);
+
+        // A PUSH_PROMISE frame shares the decoding context
+        let header_block = encoder.encode_list(hs.slice_to(2)).unwrap();
+        let split = HeaderFrames::push_promise(5, 2).split(header_block.as_slice()).unwrap();
+
+        let received = reassembler.receive(split[0].as_slice(), &mut decoder).unwrap().unwrap();
+        assert!(received.promised_stream_id == Some(2));
+        assert!(received.fields.as_slice() == hs.slice_to(2));
+    }
+
+    #[test]
+    fn padding_test() {
+        let mut decoder = Decoder::new();
+        let mut reassembler = Reassembler::new();
+
+        // Padding of 2 octets takes up the rest of the payload, so the header block is empty
+        let padded: &[u8] = &[0, 0, 3, HEADERS, END_HEADERS | PADDED, 0, 0, 0, 1, 2, 0, 0];
+        let received = reassembler.receive(padded, &mut decoder).unwrap().unwrap();
+        assert!(received.fields.len() == 0);
+
+        // An empty header block is split into a padded frame like it, with and without priority
+        for &priority in [None, Some(Priority::new(false, 1, 255))].iter() {
+            let mut frames = HeaderFrames::headers(3);
+            frames.set_padding(10);
+            match priority {
+                Some(priority) => frames.set_priority(priority),
+                None           => {}
+            }
+
+            let split = frames.split(&[]).unwrap();
+            assert!(split.len() == 1);
+
+            let received = reassembler.receive(split[0].as_slice(), &mut decoder).unwrap().unwrap();
+            assert!(received.stream_id == 3 && received.priority == priority);
+            assert!(received.fields.len() == 0);
+        }
+    }
+
+    #[test]
+    fn reassemble_error_test() {
+        let mut decoder = Decoder::new();
+        let mut reassembler = Reassembler::new();
+
+        let header_block = ~[0x82u8, ..20000];
+        let split = HeaderFrames::headers(1).split(header_block.as_slice()).unwrap();
+
+        // A CONTINUATION frame of another stream
+        let mut continuation = split[1].clone();
+        continuation[8] = 3;
+        reassembler.receive(split[0].as_slice(), &mut decoder).unwrap();
+        assert!(reassembler.receive(continuation.as_slice(), &mut decoder) == Err(InterleavedStream(1, 3)));
+        assert!(!reassembler.is_in_progress());
+
+        // A DATA frame in the middle of a header block
+        reassembler.receive(split[0].as_slice(), &mut decoder).unwrap();
+        assert!(reassembler.receive(&[0, 0, 0, 0x0, 0, 0, 0, 0, 1], &mut decoder) == Err(UnexpectedFrame(0x0)));
+
+        // A CONTINUATION frame without a header block
+        assert!(reassembler.receive(split[1].as_slice(), &mut decoder) == Err(UnexpectedFrame(CONTINUATION)));
+
+        // A frame larger than SETTINGS_MAX_FRAME_SIZE
+        let mut large = ~[0x00, 0x40, 0x01, HEADERS, END_HEADERS, 0, 0, 0, 1];
+        large.grow(16385, &0x82u8);
+        assert!(reassembler.receive(large.as_slice(), &mut decoder) == Err(FrameTooLarge(16385)));
+        reassembler.set_max_frame_size(16385).unwrap();
+        assert!(reassembler.receive(large.as_slice(), &mut decoder).unwrap().unwrap().fields.len() == 16385);
+
+        // Padding of 3 octets in a payload of 3 octets, including the Pad Length field
+        let padded: &[u8] = &[0, 0, 3, HEADERS, END_HEADERS | PADDED, 0, 0, 0, 1, 3, 0, 0];
+        assert!(reassembler.receive(padded, &mut decoder) == Err(InvalidPadding(3)));
+
+        // Stream 0, and a header block the decoder rejects
+        assert!(reassembler.receive(&[0, 0, 1, HEADERS, END_HEADERS, 0, 0, 0, 0, 0x82], &mut decoder) == Err(InvalidStreamId(0)));
+        assert!(reassembler.receive(&[0, 0, 1, HEADERS, END_HEADERS, 0, 0, 0, 1, 0x80], &mut decoder) ==
+                Err(CompressionFailed(InvalidIndex(0, 0))));
+    }
+
+    #[test]
+    fn header_block_too_large_test() {
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+        let mut reassembler = Reassembler::new();
+        reassembler.set_max_header_block_size(16384);
+
+        // The first frame ends in the middle of the literal
+        let hs = fields(&[("cookie", "a".repeat(40000).as_slice())]);
+        let header_block = encoder.encode_list(hs.as_slice()).unwrap();
+        let split = HeaderFrames::headers(1).split(header_block.as_slice()).unwrap();
+
+        assert!(reassembler.receive(split[0].as_slice(), &mut decoder).unwrap().is_none());
+        assert!(reassembler.receive(split[1].as_slice(), &mut decoder) ==
+                Err(HeaderBlockTooLarge(16384 + split[1].len() - 9)));
+        assert!(!reassembler.is_in_progress());
+
+        // The decoder starts over with the next header block
+        let received = reassembler.receive(&[0, 0, 1, HEADERS, END_HEADERS, 0, 0, 0, 3, 0x82], &mut decoder).unwrap().unwrap();
+        assert!(received.fields == fields(&[(":method", "GET")]));
+
+        // A header list beyond the maximum of the decoder is only a stream error
+        decoder.set_max_header_list_size(50);
+        let frame: &[u8] = &[0, 0, 2, HEADERS, END_HEADERS, 0, 0, 0, 5, 0x82, 0x84];
+        assert!(reassembler.receive(frame, &mut decoder) == Err(HeaderListTooLarge(5)));
+
+        let received = reassembler.receive(&[0, 0, 1, HEADERS, END_HEADERS, 0, 0, 0, 7, 0x84], &mut decoder).unwrap().unwrap();
+        assert!(received.fields == fields(&[(":path", "/")]));
+    }
+}
@@ -1,8 +1,12 @@
 use collections::HashMap;
 
+use header_field::HeaderField;
+
 #[deriving(Clone)]
 pub struct HeaderCollection {
-    pub header_fields: HashMap<~str, ~str>,
+    // Name -> Values, in the order they were decoded, each with whether
+    // it was sent as a literal never indexed
+    pub header_fields: HashMap<~[u8], ~[(~[u8], bool)]>,
 }
 
 impl HeaderCollection {
@@ -12,28 +16,74 @@ impl HeaderCollection {
         }
     }
 
-    pub fn add(&mut self, key: ~str, value: ~str) {
-        self.header_fields.insert_or_update_with(key, value.clone(), |_, v| *v = *v + "\0" + value.clone());
+    pub fn add(&mut self, key: ~[u8], value: ~[u8]) {
+        self.add_value(key, value, false);
+    }
+
+    // Add a header field which was sent as a literal never indexed
+    pub fn add_sensitive(&mut self, key: ~[u8], value: ~[u8]) {
+        self.add_value(key, value, true);
     }
 
-    pub fn get(&self, key: &~str) -> ~[~str] {
-        let mut values = ~[];
+    fn add_value(&mut self, key: ~[u8], value: ~[u8], sensitive: bool) {
+        self.header_fields.insert_or_update_with(key, ~[(value.clone(), sensitive)], |_, v| v.push((value.clone(), sensitive)));
+    }
 
-        match self.header_fields.find(key) {
-            Some(value) => {
-                for val in value.split('\0') {
-                    values.push(val.clone().to_owned());                    
-                }
-            },
-            None => {}
+    pub fn get(&self, key: &[u8]) -> ~[~[u8]] {
+        match self.header_fields.find_equiv(&key) {
+            Some(values) => values.iter().map(|&(ref value, _)| value.clone()).collect(),
+            None => ~[]
         }
+    }
 
-        values
+    // The header fields with the given name, in order, each with its own sensitive flag
+    pub fn get_fields(&self, key: &[u8]) -> ~[HeaderField] {
+        match self.header_fields.find_equiv(&key) {
+            Some(values) => values.iter().map(|&(ref value, sensitive)| {
+                let mut hf = HeaderField::from_bytes(key.to_owned(), value.clone());
+                hf.sensitive = sensitive;
+                hf
+            }).collect(),
+            None => ~[]
+        }
+    }
+
+    // Whether the given value of the header field was sent as a literal never indexed.
+    // Such a header field must be re-encoded as a literal never indexed as well.
+    // Other values with the same name need not be sensitive.
+    pub fn is_sensitive(&self, key: &[u8], value: &[u8]) -> bool {
+        match self.header_fields.find_equiv(&key) {
+            Some(values) => values.iter().any(|&(ref v, sensitive)| sensitive && v.as_slice() == value),
+            None => false
+        }
     }
 
     pub fn merge(&mut self, other: HeaderCollection) {
-        for (key, value) in other.header_fields.iter() {
-            self.add(key.to_owned(), value.to_owned());
+        for (key, values) in other.header_fields.iter() {
+            for &(ref value, sensitive) in values.iter() {
+                self.add_value(key.clone(), value.clone(), sensitive);
+            }
         }
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn header_collection_test() {
+    // cookie: a was sent as never indexed, cookie: b was not
+    let mut header_list = HeaderCollection::new();
+    header_list.add_sensitive(bytes!("cookie").to_owned(), bytes!("a").to_owned());
+    header_list.add(bytes!("cookie").to_owned(), bytes!("b").to_owned());
+
+    assert!(header_list.get(bytes!("cookie")) == ~[bytes!("a").to_owned(), bytes!("b").to_owned()]);
+    assert!(header_list.is_sensitive(bytes!("cookie"), bytes!("a")));
+    assert!(!header_list.is_sensitive(bytes!("cookie"), bytes!("b")));
+
+    // Merging keeps the flag of each value
+    let mut merged = HeaderCollection::new();
+    merged.merge(header_list);
+
+    let fields = merged.get_fields(bytes!("cookie"));
+    assert!(fields.len() == 2);
+    assert!(fields[0].sensitive && !fields[1].sensitive);
+    assert!(!merged.is_sensitive(bytes!("cookie"), bytes!("b")));
+}
@@ -1,19 +1,68 @@
+use std::str;
+use std::hash::Hash;
+use std::rc::Rc;
+
 /// Struct representing an HTTP/2 header field.
-#[deriving(Eq, TotalEq, Hash, Clone)]
+/// Names and values are octets, since HTTP field values are not necessarily UTF-8
+/// (e.g. obs-text). Use `key_str` and `value_str` to get them as strings.
+/// A sensitive header field is never added to a header table, neither by the
+/// encoder nor by any intermediary re-encoding it (RFC 7541 section 7.1.3).
+/// Header fields are equal, and hash alike, if their names and values are,
+/// whether they are sensitive or not.
+#[deriving(TotalEq, Clone, Show)]
 pub struct HeaderField {
-    key: ~str,
-    value: ~str
+    key: ~[u8],
+    value: ~[u8],
+    sensitive: bool
+}
+
+impl Eq for HeaderField {
+    fn eq(&self, other: &HeaderField) -> bool {
+        self.key == other.key && self.value == other.value
+    }
+}
+
+impl<S: Writer> Hash<S> for HeaderField {
+    fn hash(&self, state: &mut S) {
+        self.key.hash(state);
+        self.value.hash(state);
+    }
 }
 
 impl HeaderField {
     /// Create a header field.
     pub fn new(key: ~str, value: ~str) -> HeaderField {
+        HeaderField::from_bytes(key.into_bytes(), value.into_bytes())
+    }
+
+    /// Create a header field from octets.
+    pub fn from_bytes(key: ~[u8], value: ~[u8]) -> HeaderField {
         HeaderField {
             key: key,
             value: value,
+            sensitive: false,
+        }
+    }
+
+    /// Create a sensitive header field, which is encoded as a literal never indexed.
+    pub fn new_sensitive(key: ~str, value: ~str) -> HeaderField {
+        HeaderField {
+            key: key.into_bytes(),
+            value: value.into_bytes(),
+            sensitive: true,
         }
     }
 
+    /// The name of the header field, or None if it is not valid UTF-8.
+    pub fn key_str<'a>(&'a self) -> Option<&'a str> {
+        str::from_utf8(self.key.as_slice())
+    }
+
+    /// The value of the header field, or None if it is not valid UTF-8.
+    pub fn value_str<'a>(&'a self) -> Option<&'a str> {
+        str::from_utf8(self.value.as_slice())
+    }
+
     /// Returns the size of the header field.
     /// The size of a header field is the sum of its name's length in octets (bytes) plus its value's length in octets plus 32.
     /// The 32 octets accounts for structure overhead (two pointers).        
@@ -22,4 +71,95 @@ impl HeaderField {
         let value_size = self.value.len();
         key_size + value_size + 32
     }
-}
\ No newline at end of file
+}
+
+/// Octets which are either borrowed or owned, like `std::str::MaybeOwned` for strings,
+/// or the name or value of a header table entry, which is shared rather than copied.
+pub enum MaybeOwnedBytes<'a> {
+    Slice(&'a [u8]),
+    Owned(~[u8]),
+    SharedKey(Rc<HeaderField>),
+    SharedValue(Rc<HeaderField>),
+}
+
+impl<'a> MaybeOwnedBytes<'a> {
+    pub fn as_slice<'b>(&'b self) -> &'b [u8] {
+        match *self {
+            Slice(bytes)           => bytes,
+            Owned(ref bytes)       => bytes.as_slice(),
+            SharedKey(ref field)   => field.key.as_slice(),
+            SharedValue(ref field) => field.value.as_slice(),
+        }
+    }
+
+    pub fn into_owned(self) -> ~[u8] {
+        match self {
+            Slice(bytes)       => bytes.to_owned(),
+            Owned(bytes)       => bytes,
+            SharedKey(field)   => field.key.clone(),
+            SharedValue(field) => field.value.clone(),
+        }
+    }
+}
+
+/// A decoded header field which borrows its name and value from the header block
+/// (or the static header table) where possible.
+/// Only Huffman-decoded strings are owned, and header table entries are shared.
+/// `sensitive` is set if the header field was sent as a literal never indexed.
+pub struct HeaderFieldRef<'a> {
+    key: MaybeOwnedBytes<'a>,
+    value: MaybeOwnedBytes<'a>,
+    sensitive: bool
+}
+
+impl<'a> HeaderFieldRef<'a> {
+    /// Create a header field reference.
+    pub fn new(key: MaybeOwnedBytes<'a>, value: MaybeOwnedBytes<'a>) -> HeaderFieldRef<'a> {
+        HeaderFieldRef {
+            key: key,
+            value: value,
+            sensitive: false,
+        }
+    }
+
+    /// The name of the header field, or None if it is not valid UTF-8.
+    pub fn key_str<'b>(&'b self) -> Option<&'b str> {
+        str::from_utf8(self.key.as_slice())
+    }
+
+    /// The value of the header field, or None if it is not valid UTF-8.
+    pub fn value_str<'b>(&'b self) -> Option<&'b str> {
+        str::from_utf8(self.value.as_slice())
+    }
+
+    /// Copy the name and value into an owned header field.
+    pub fn to_header_field(&self) -> HeaderField {
+        HeaderField {
+            key: self.key.as_slice().to_owned(),
+            value: self.value.as_slice().to_owned(),
+            sensitive: self.sensitive,
+        }
+    }
+
+    /// Returns the size of the header field, like `HeaderField::size`.
+    pub fn size(&self) -> uint {
+        self.key.as_slice().len() + self.value.as_slice().len() + 32
+    }
+}
+
+#[test]
+fn header_field_test() {
+    let h0 = HeaderField::new(~"foo", ~"bar");
+    assert!(h0.key_str() == Some("foo") && h0.value_str() == Some("bar"));
+    assert!(h0.size() == 38);
+
+    // An obs-text value, e.g. a Latin-1 filename
+    let h1 = HeaderField::from_bytes((~"content-disposition").into_bytes(), ~[0x66, 0xE6, 0x72]);
+    assert!(h1.key_str() == Some("content-disposition"));
+    assert!(h1.value_str().is_none());
+    assert!(h1.size() == 19 + 3 + 32);
+
+    // The sensitive flag is not compared
+    let h2 = HeaderField::new_sensitive(~"foo", ~"bar");
+    assert!(h0 == h2 && h2.sensitive && !h0.sensitive);
+}
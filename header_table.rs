@@ -1,17 +1,84 @@
+use std::rc::Rc;
+use collections::{RingBuf, Deque};
+use collections::ringbuf;
+
 use header_field::HeaderField;
 
+// The dynamic header table.
+// The newest header field is at the front of the ring buffer,
+// so HPACK index 1 is position 0. The size in octets is kept up to date
+// as header fields are added and removed.
+// Every header field also has an absolute insertion number, which
+// unlike its index does not change when other fields are added.
+// Entries are reference counted, so decoded header fields can share them.
 pub struct HeaderTable {
-    priv fields: ~[HeaderField],
-    priv max_size: uint
+    priv fields: RingBuf<Rc<HeaderField>>,
+    priv size: uint,
+    priv max_size: uint,
+    priv insert_count: uint
 }
 
 pub static DEFAULT_HEADER_TABLE_SIZE: uint = 4096; 
 
+/// A read-only view of the header table of an `Encoder` or a `Decoder`,
+/// e.g. to inspect its entries and size.
+pub struct HeaderTableView<'a> {
+    priv table: &'a HeaderTable,
+}
+
+/// An iterator over the entries of a header table, from index 1 (the newest) to the oldest.
+pub struct TableEntries<'a> {
+    priv iter: ringbuf::Items<'a, Rc<HeaderField>>,
+}
+
+impl<'a> HeaderTableView<'a> {
+    /// The number of entries.
+    pub fn len(&self) -> uint {
+        self.table.len()
+    }
+
+    /// The size in octets, i.e. the sum of the sizes of the entries.
+    pub fn size(&self) -> uint {
+        self.table.size()
+    }
+
+    /// The maximum size in octets.
+    pub fn max_size(&self) -> uint {
+        self.table.get_max_size()
+    }
+
+    /// The entry at an index of the header table, from 1 on. None if out of bounds.
+    pub fn get(&self, index: uint) -> Option<&'a HeaderField> {
+        let table: &'a HeaderTable = self.table;
+
+        if index < 1 || index > table.fields.len() {
+            return None;
+        }
+
+        Some(&**table.fields.get(index - 1))
+    }
+
+    /// Iterate over the entries, the newest (index 1) first.
+    pub fn iter(&self) -> TableEntries<'a> {
+        let table: &'a HeaderTable = self.table;
+
+        table.iter()
+    }
+}
+
+impl<'a> Iterator<&'a HeaderField> for TableEntries<'a> {
+    fn next(&mut self) -> Option<&'a HeaderField> {
+        self.iter.next().map(|field| &**field)
+    }
+}
+
 impl HeaderTable {
     pub fn new(max_size: uint) -> HeaderTable {
         HeaderTable {
-            fields: ~[],
-            max_size: max_size
+            fields: RingBuf::new(),
+            size: 0,
+            max_size: max_size,
+            insert_count: 0
         }
     }
 
@@ -24,32 +91,10 @@ impl HeaderTable {
     }
 
 
-    // Search for a header field.
-    // Return None if not found.
-    // Return Some(index, full_match) where full_match is true
-    // if both the name _and_ value match.
-    // If only a partial match is found it will return the last 
-    // partial match found.
-    pub fn find(&self, hf: HeaderField) -> Option<(uint, bool)> {
-        let mut partial_match = None; 
-
-        for i in range(0, self.fields.len()) {
-            if self.fields[i].key == hf.key {
-                if self.fields[i].value == hf.value {
-                    return Some((i + 1, true));
-                } else {
-                    partial_match = Some((i + 1, false));
-                }
-            }
-        }
-
-        partial_match
-    }
-
     // The size of the header table in octets
     // i.e. the sum of header field's sizes
     pub fn size(&self) -> uint {
-        self.fields.iter().fold(0, |r, f| r + f.size())
+        self.size
     }
 
     // Number of header fields in the table
@@ -58,26 +103,86 @@ impl HeaderTable {
     }
 
     // Prepend a header field to the table
-    pub fn add(&mut self, field: HeaderField) {
-        self.fields.unshift(field);
+    // Returns the insertion number of the header field
+    pub fn add(&mut self, field: HeaderField) -> uint {
+        self.size += field.size();
+        self.fields.push_front(Rc::new(field));
+        self.insert_count += 1;
+
+        self.insert_count - 1
+    }
+
+    // Return the index of the header field with insertion number 'insertion'
+    // Returns None if it has been evicted, or not been added yet
+    pub fn index_of(&self, insertion: uint) -> Option<uint> {
+        if insertion >= self.insert_count || self.insert_count - insertion > self.fields.len() {
+            return None;
+        }
+
+        Some(self.insert_count - insertion)
+    }
+
+    // The insertion number of the oldest header field
+    pub fn oldest(&self) -> uint {
+        self.insert_count - self.fields.len()
     }
 
     // Return the header field at 'index' - 1
     // Returns None if out of bounds
     // Remeber, HPACK uses 1-indexing!!!
-    pub fn get(&mut self, index: uint) -> Option<HeaderField> {
+    pub fn get(&self, index: uint) -> Option<HeaderField> {
+        match self.get_shared(index) {
+            Some(field) => Some((*field).clone()),
+            None        => None,
+        }
+    }
+
+    // Same as get, but the header field is shared instead of copied
+    pub fn get_shared(&self, index: uint) -> Option<Rc<HeaderField>> {
         if index < 1 || index > self.fields.len() {
             return None;
         }
 
-        Some(self.fields[index - 1].clone())
+        Some(self.fields.get(index - 1).clone())
+    }
+
+    // A read-only view of the header table, which is all that is handed out of the crate
+    pub fn view<'a>(&'a self) -> HeaderTableView<'a> {
+        HeaderTableView { table: self }
     }
 
-    // Remove and return a header field at an index
-    // and shift all elements after the index one to the left
-    pub fn remove(&mut self, index: uint) -> Option<HeaderField> {
-        // Remember, HPACK uses 1-indexing!!!
-        self.fields.remove(index - 1)
+    // Iterate over the header fields from index 1, the newest, to the oldest
+    pub fn iter<'a>(&'a self) -> TableEntries<'a> {
+        TableEntries { iter: self.fields.iter() }
+    }
+
+    // Remove and return the newest header field (index 1), undoing `add`
+    pub fn remove_newest(&mut self) -> Option<Rc<HeaderField>> {
+        match self.fields.pop_front() {
+            Some(field) => {
+                self.size -= field.size();
+                self.insert_count -= 1;
+                Some(field)
+            },
+            None => None
+        }
+    }
+
+    // Append a header field as the oldest one, undoing `remove_oldest`
+    pub fn add_oldest(&mut self, field: Rc<HeaderField>) {
+        self.size += field.size();
+        self.fields.push_back(field);
+    }
+
+    // Remove and return the oldest header field (the one with the highest index)
+    pub fn remove_oldest(&mut self) -> Option<Rc<HeaderField>> {
+        match self.fields.pop_back() {
+            Some(field) => {
+                self.size -= field.size();
+                Some(field)
+            },
+            None => None
+        }
     }
 }
 
@@ -92,27 +197,74 @@ fn header_table_test() {
     let s2 = h2.size();
 
     // max_size does not play a role in this test 
-    let mut ht = ~HeaderTable { fields: ~[], max_size: 0 };
+    let mut ht = ~HeaderTable::new(0);
 
-    ht.add(h0);
-    ht.add(h1);
-    ht.add(h2);
+    assert!(ht.add(h0) == 0);
+    assert!(ht.add(h1) == 1);
+    assert!(ht.add(h2) == 2);
 
     assert!(ht.len() == 3);
     assert!(ht.size() == s0 + s1 + s2);
 
     // Check the order of the header fields
-    assert!(ht.fields[0].key == ~"foo2");
-    assert!(ht.fields[1].key == ~"foo1");
-    assert!(ht.fields[2].key == ~"foo");
+    assert!(ht.get(1).unwrap().key_str() == Some("foo2"));
+    assert!(ht.get(2).unwrap().key_str() == Some("foo1"));
+    assert!(ht.get(3).unwrap().key_str() == Some("foo"));
+    assert!(ht.get(0).is_none());
+    assert!(ht.get(4).is_none());
 
-    ht.remove(2); // Remove the _second_ element.
+    let keys: ~[Option<&str>] = ht.iter().map(|hf| hf.key_str()).collect();
+    assert!(keys == ~[Some("foo2"), Some("foo1"), Some("foo")]);
 
-    // Check that the second header field is removed
-    // and that the last one has been shifted to the left
+    assert!(ht.index_of(2) == Some(1));
+    assert!(ht.index_of(0) == Some(3));
+    assert!(ht.index_of(3).is_none());
+
+    assert!(ht.remove_oldest().unwrap().key_str() == Some("foo"));
+
+    // Check that the oldest header field is removed
+    // and that the size is updated
+    assert!(ht.len() == 2);
+    assert!(ht.size() == s1 + s2);
+
+    assert!(ht.get(1).unwrap().key_str() == Some("foo2"));
+    assert!(ht.get(2).unwrap().key_str() == Some("foo1"));
+
+    // Evicted header fields have no index
+    assert!(ht.index_of(0).is_none());
+    assert!(ht.oldest() == 1);
+
+    // Undo adding foo2 and evicting foo
+    assert!(ht.remove_newest().unwrap().key_str() == Some("foo2"));
+    ht.add_oldest(Rc::new(HeaderField::new(~"foo", ~"bar0")));
     assert!(ht.len() == 2);
-    assert!(ht.size() == s0 + s2);
+    assert!(ht.size() == s0 + s1);
+    assert!(ht.index_of(0) == Some(2));
+    assert!(ht.index_of(2).is_none());
+
+    ht.remove_oldest();
+    ht.remove_oldest();
+
+    assert!(ht.len() == 0);
+    assert!(ht.size() == 0);
+    assert!(ht.remove_oldest().is_none());
+}
+
+#[test]
+fn large_header_table_test() {
+    let mut ht = ~HeaderTable::new(65536);
+
+    // Fill a 64 KiB header table and keep it full, like an encoder would
+    for i in range(0u, 10000) {
+        ht.add(HeaderField::new(format!("name{}", i), ~"value"));
 
-    assert!(ht.fields[0].key == ~"foo2");
-    assert!(ht.fields[1].key == ~"foo");
-}
\ No newline at end of file
+        while ht.size() > ht.get_max_size() {
+            ht.remove_oldest();
+        }
+    }
+
+    assert!(ht.size() <= 65536);
+    assert!(ht.get(1).unwrap().key_str() == Some("name9999"));
+    let oldest = format!("name{}", 10000 - ht.len());
+    assert!(ht.get(ht.len()).unwrap().key_str() == Some(oldest.as_slice()));
+}
@@ -0,0 +1,1701 @@
+// Huffman decoding table generated from the Huffman code of RFC 7541:
+// http://tools.ietf.org/html/rfc7541#appendix-B
+//
+// The decoder is a finite state machine consuming 4 bits at a time.
+// A state is an internal node of the Huffman tree (state 0 is the root).
+// HUFFMAN_DECODE_TABLE[state][nibble] is (next state, flags, symbol).
+//
+// The internal nodes are numbered breadth-first from the root, the 0 branch first.
+// `build_decode_table` at the bottom of this file builds the table from HUFFMAN_CODES,
+// and `huffman_decode_table_test` checks it against this one. If they differ, e.g.
+// after a change of HUFFMAN_CODES, the test prints the rebuilt table in the format
+// of this file, to be pasted between the brackets below.
+
+#[cfg(test)]
+use huffman::huffman_codes::HUFFMAN_CODES;
+
+// The nibble completes the EOS symbol, which must never be decoded
+pub static HUFFMAN_FAIL: u8 = 1;
+// The nibble completes the symbol
+pub static HUFFMAN_SYMBOL: u8 = 2;
+// The bits following the last symbol are a valid padding (fewer than 8 ones)
+pub static HUFFMAN_ACCEPTED: u8 = 4;
+
+pub static HUFFMAN_DECODE_TABLE: [[(u8, u8, u8), ..16], ..256] = [
+    /*   0 */ [
+        (15, 0, 0), (16, 0, 0), (17, 0, 0), (18, 0, 0),
+        (19, 0, 0), (20, 0, 0), (21, 0, 0), (22, 0, 0),
+        (23, 0, 0), (24, 0, 0), (25, 0, 0), (26, 0, 0),
+        (27, 0, 0), (28, 0, 0), (29, 0, 0), (30, 4, 0),
+    ],
+    /*   1 */ [
+        (0, 6, 48), (0, 6, 49), (0, 6, 50), (0, 6, 97),
+        (0, 6, 99), (0, 6, 101), (0, 6, 105), (0, 6, 111),
+        (0, 6, 115), (0, 6, 116), (31, 0, 0), (32, 0, 0),
+        (33, 0, 0), (34, 0, 0), (35, 0, 0), (36, 0, 0),
+    ],
+    /*   2 */ [
+        (37, 0, 0), (38, 0, 0), (39, 0, 0), (40, 0, 0),
+        (41, 0, 0), (42, 0, 0), (43, 0, 0), (44, 0, 0),
+        (45, 0, 0), (46, 0, 0), (47, 0, 0), (48, 0, 0),
+        (49, 0, 0), (50, 0, 0), (51, 0, 0), (52, 4, 0),
+    ],
+    /*   3 */ [
+        (1, 2, 48), (2, 6, 48), (1, 2, 49), (2, 6, 49),
+        (1, 2, 50), (2, 6, 50), (1, 2, 97), (2, 6, 97),
+        (1, 2, 99), (2, 6, 99), (1, 2, 101), (2, 6, 101),
+        (1, 2, 105), (2, 6, 105), (1, 2, 111), (2, 6, 111),
+    ],
+    /*   4 */ [
+        (1, 2, 115), (2, 6, 115), (1, 2, 116), (2, 6, 116),
+        (0, 6, 32), (0, 6, 37), (0, 6, 45), (0, 6, 46),
+        (0, 6, 47), (0, 6, 51), (0, 6, 52), (0, 6, 53),
+        (0, 6, 54), (0, 6, 55), (0, 6, 56), (0, 6, 57),
+    ],
+    /*   5 */ [
+        (0, 6, 61), (0, 6, 65), (0, 6, 95), (0, 6, 98),
+        (0, 6, 100), (0, 6, 102), (0, 6, 103), (0, 6, 104),
+        (0, 6, 108), (0, 6, 109), (0, 6, 110), (0, 6, 112),
+        (0, 6, 114), (0, 6, 117), (53, 0, 0), (54, 0, 0),
+    ],
+    /*   6 */ [
+        (55, 0, 0), (56, 0, 0), (57, 0, 0), (58, 0, 0),
+        (59, 0, 0), (60, 0, 0), (61, 0, 0), (62, 0, 0),
+        (63, 0, 0), (64, 0, 0), (65, 0, 0), (66, 0, 0),
+        (67, 0, 0), (68, 0, 0), (69, 0, 0), (70, 4, 0),
+    ],
+    /*   7 */ [
+        (3, 2, 48), (4, 2, 48), (5, 2, 48), (6, 6, 48),
+        (3, 2, 49), (4, 2, 49), (5, 2, 49), (6, 6, 49),
+        (3, 2, 50), (4, 2, 50), (5, 2, 50), (6, 6, 50),
+        (3, 2, 97), (4, 2, 97), (5, 2, 97), (6, 6, 97),
+    ],
+    /*   8 */ [
+        (3, 2, 99), (4, 2, 99), (5, 2, 99), (6, 6, 99),
+        (3, 2, 101), (4, 2, 101), (5, 2, 101), (6, 6, 101),
+        (3, 2, 105), (4, 2, 105), (5, 2, 105), (6, 6, 105),
+        (3, 2, 111), (4, 2, 111), (5, 2, 111), (6, 6, 111),
+    ],
+    /*   9 */ [
+        (3, 2, 115), (4, 2, 115), (5, 2, 115), (6, 6, 115),
+        (3, 2, 116), (4, 2, 116), (5, 2, 116), (6, 6, 116),
+        (1, 2, 32), (2, 6, 32), (1, 2, 37), (2, 6, 37),
+        (1, 2, 45), (2, 6, 45), (1, 2, 46), (2, 6, 46),
+    ],
+    /*  10 */ [
+        (1, 2, 47), (2, 6, 47), (1, 2, 51), (2, 6, 51),
+        (1, 2, 52), (2, 6, 52), (1, 2, 53), (2, 6, 53),
+        (1, 2, 54), (2, 6, 54), (1, 2, 55), (2, 6, 55),
+        (1, 2, 56), (2, 6, 56), (1, 2, 57), (2, 6, 57),
+    ],
+    /*  11 */ [
+        (1, 2, 61), (2, 6, 61), (1, 2, 65), (2, 6, 65),
+        (1, 2, 95), (2, 6, 95), (1, 2, 98), (2, 6, 98),
+        (1, 2, 100), (2, 6, 100), (1, 2, 102), (2, 6, 102),
+        (1, 2, 103), (2, 6, 103), (1, 2, 104), (2, 6, 104),
+    ],
+    /*  12 */ [
+        (1, 2, 108), (2, 6, 108), (1, 2, 109), (2, 6, 109),
+        (1, 2, 110), (2, 6, 110), (1, 2, 112), (2, 6, 112),
+        (1, 2, 114), (2, 6, 114), (1, 2, 117), (2, 6, 117),
+        (0, 6, 58), (0, 6, 66), (0, 6, 67), (0, 6, 68),
+    ],
+    /*  13 */ [
+        (0, 6, 69), (0, 6, 70), (0, 6, 71), (0, 6, 72),
+        (0, 6, 73), (0, 6, 74), (0, 6, 75), (0, 6, 76),
+        (0, 6, 77), (0, 6, 78), (0, 6, 79), (0, 6, 80),
+        (0, 6, 81), (0, 6, 82), (0, 6, 83), (0, 6, 84),
+    ],
+    /*  14 */ [
+        (0, 6, 85), (0, 6, 86), (0, 6, 87), (0, 6, 89),
+        (0, 6, 106), (0, 6, 107), (0, 6, 113), (0, 6, 118),
+        (0, 6, 119), (0, 6, 120), (0, 6, 121), (0, 6, 122),
+        (71, 0, 0), (72, 0, 0), (73, 0, 0), (74, 4, 0),
+    ],
+    /*  15 */ [
+        (7, 2, 48), (8, 2, 48), (9, 2, 48), (10, 2, 48),
+        (11, 2, 48), (12, 2, 48), (13, 2, 48), (14, 6, 48),
+        (7, 2, 49), (8, 2, 49), (9, 2, 49), (10, 2, 49),
+        (11, 2, 49), (12, 2, 49), (13, 2, 49), (14, 6, 49),
+    ],
+    /*  16 */ [
+        (7, 2, 50), (8, 2, 50), (9, 2, 50), (10, 2, 50),
+        (11, 2, 50), (12, 2, 50), (13, 2, 50), (14, 6, 50),
+        (7, 2, 97), (8, 2, 97), (9, 2, 97), (10, 2, 97),
+        (11, 2, 97), (12, 2, 97), (13, 2, 97), (14, 6, 97),
+    ],
+    /*  17 */ [
+        (7, 2, 99), (8, 2, 99), (9, 2, 99), (10, 2, 99),
+        (11, 2, 99), (12, 2, 99), (13, 2, 99), (14, 6, 99),
+        (7, 2, 101), (8, 2, 101), (9, 2, 101), (10, 2, 101),
+        (11, 2, 101), (12, 2, 101), (13, 2, 101), (14, 6, 101),
+    ],
+    /*  18 */ [
+        (7, 2, 105), (8, 2, 105), (9, 2, 105), (10, 2, 105),
+        (11, 2, 105), (12, 2, 105), (13, 2, 105), (14, 6, 105),
+        (7, 2, 111), (8, 2, 111), (9, 2, 111), (10, 2, 111),
+        (11, 2, 111), (12, 2, 111), (13, 2, 111), (14, 6, 111),
+    ],
+    /*  19 */ [
+        (7, 2, 115), (8, 2, 115), (9, 2, 115), (10, 2, 115),
+        (11, 2, 115), (12, 2, 115), (13, 2, 115), (14, 6, 115),
+        (7, 2, 116), (8, 2, 116), (9, 2, 116), (10, 2, 116),
+        (11, 2, 116), (12, 2, 116), (13, 2, 116), (14, 6, 116),
+    ],
+    /*  20 */ [
+        (3, 2, 32), (4, 2, 32), (5, 2, 32), (6, 6, 32),
+        (3, 2, 37), (4, 2, 37), (5, 2, 37), (6, 6, 37),
+        (3, 2, 45), (4, 2, 45), (5, 2, 45), (6, 6, 45),
+        (3, 2, 46), (4, 2, 46), (5, 2, 46), (6, 6, 46),
+    ],
+    /*  21 */ [
+        (3, 2, 47), (4, 2, 47), (5, 2, 47), (6, 6, 47),
+        (3, 2, 51), (4, 2, 51), (5, 2, 51), (6, 6, 51),
+        (3, 2, 52), (4, 2, 52), (5, 2, 52), (6, 6, 52),
+        (3, 2, 53), (4, 2, 53), (5, 2, 53), (6, 6, 53),
+    ],
+    /*  22 */ [
+        (3, 2, 54), (4, 2, 54), (5, 2, 54), (6, 6, 54),
+        (3, 2, 55), (4, 2, 55), (5, 2, 55), (6, 6, 55),
+        (3, 2, 56), (4, 2, 56), (5, 2, 56), (6, 6, 56),
+        (3, 2, 57), (4, 2, 57), (5, 2, 57), (6, 6, 57),
+    ],
+    /*  23 */ [
+        (3, 2, 61), (4, 2, 61), (5, 2, 61), (6, 6, 61),
+        (3, 2, 65), (4, 2, 65), (5, 2, 65), (6, 6, 65),
+        (3, 2, 95), (4, 2, 95), (5, 2, 95), (6, 6, 95),
+        (3, 2, 98), (4, 2, 98), (5, 2, 98), (6, 6, 98),
+    ],
+    /*  24 */ [
+        (3, 2, 100), (4, 2, 100), (5, 2, 100), (6, 6, 100),
+        (3, 2, 102), (4, 2, 102), (5, 2, 102), (6, 6, 102),
+        (3, 2, 103), (4, 2, 103), (5, 2, 103), (6, 6, 103),
+        (3, 2, 104), (4, 2, 104), (5, 2, 104), (6, 6, 104),
+    ],
+    /*  25 */ [
+        (3, 2, 108), (4, 2, 108), (5, 2, 108), (6, 6, 108),
+        (3, 2, 109), (4, 2, 109), (5, 2, 109), (6, 6, 109),
+        (3, 2, 110), (4, 2, 110), (5, 2, 110), (6, 6, 110),
+        (3, 2, 112), (4, 2, 112), (5, 2, 112), (6, 6, 112),
+    ],
+    /*  26 */ [
+        (3, 2, 114), (4, 2, 114), (5, 2, 114), (6, 6, 114),
+        (3, 2, 117), (4, 2, 117), (5, 2, 117), (6, 6, 117),
+        (1, 2, 58), (2, 6, 58), (1, 2, 66), (2, 6, 66),
+        (1, 2, 67), (2, 6, 67), (1, 2, 68), (2, 6, 68),
+    ],
+    /*  27 */ [
+        (1, 2, 69), (2, 6, 69), (1, 2, 70), (2, 6, 70),
+        (1, 2, 71), (2, 6, 71), (1, 2, 72), (2, 6, 72),
+        (1, 2, 73), (2, 6, 73), (1, 2, 74), (2, 6, 74),
+        (1, 2, 75), (2, 6, 75), (1, 2, 76), (2, 6, 76),
+    ],
+    /*  28 */ [
+        (1, 2, 77), (2, 6, 77), (1, 2, 78), (2, 6, 78),
+        (1, 2, 79), (2, 6, 79), (1, 2, 80), (2, 6, 80),
+        (1, 2, 81), (2, 6, 81), (1, 2, 82), (2, 6, 82),
+        (1, 2, 83), (2, 6, 83), (1, 2, 84), (2, 6, 84),
+    ],
+    /*  29 */ [
+        (1, 2, 85), (2, 6, 85), (1, 2, 86), (2, 6, 86),
+        (1, 2, 87), (2, 6, 87), (1, 2, 89), (2, 6, 89),
+        (1, 2, 106), (2, 6, 106), (1, 2, 107), (2, 6, 107),
+        (1, 2, 113), (2, 6, 113), (1, 2, 118), (2, 6, 118),
+    ],
+    /*  30 */ [
+        (1, 2, 119), (2, 6, 119), (1, 2, 120), (2, 6, 120),
+        (1, 2, 121), (2, 6, 121), (1, 2, 122), (2, 6, 122),
+        (0, 6, 38), (0, 6, 42), (0, 6, 44), (0, 6, 59),
+        (0, 6, 88), (0, 6, 90), (75, 0, 0), (76, 0, 0),
+    ],
+    /*  31 */ [
+        (7, 2, 32), (8, 2, 32), (9, 2, 32), (10, 2, 32),
+        (11, 2, 32), (12, 2, 32), (13, 2, 32), (14, 6, 32),
+        (7, 2, 37), (8, 2, 37), (9, 2, 37), (10, 2, 37),
+        (11, 2, 37), (12, 2, 37), (13, 2, 37), (14, 6, 37),
+    ],
+    /*  32 */ [
+        (7, 2, 45), (8, 2, 45), (9, 2, 45), (10, 2, 45),
+        (11, 2, 45), (12, 2, 45), (13, 2, 45), (14, 6, 45),
+        (7, 2, 46), (8, 2, 46), (9, 2, 46), (10, 2, 46),
+        (11, 2, 46), (12, 2, 46), (13, 2, 46), (14, 6, 46),
+    ],
+    /*  33 */ [
+        (7, 2, 47), (8, 2, 47), (9, 2, 47), (10, 2, 47),
+        (11, 2, 47), (12, 2, 47), (13, 2, 47), (14, 6, 47),
+        (7, 2, 51), (8, 2, 51), (9, 2, 51), (10, 2, 51),
+        (11, 2, 51), (12, 2, 51), (13, 2, 51), (14, 6, 51),
+    ],
+    /*  34 */ [
+        (7, 2, 52), (8, 2, 52), (9, 2, 52), (10, 2, 52),
+        (11, 2, 52), (12, 2, 52), (13, 2, 52), (14, 6, 52),
+        (7, 2, 53), (8, 2, 53), (9, 2, 53), (10, 2, 53),
+        (11, 2, 53), (12, 2, 53), (13, 2, 53), (14, 6, 53),
+    ],
+    /*  35 */ [
+        (7, 2, 54), (8, 2, 54), (9, 2, 54), (10, 2, 54),
+        (11, 2, 54), (12, 2, 54), (13, 2, 54), (14, 6, 54),
+        (7, 2, 55), (8, 2, 55), (9, 2, 55), (10, 2, 55),
+        (11, 2, 55), (12, 2, 55), (13, 2, 55), (14, 6, 55),
+    ],
+    /*  36 */ [
+        (7, 2, 56), (8, 2, 56), (9, 2, 56), (10, 2, 56),
+        (11, 2, 56), (12, 2, 56), (13, 2, 56), (14, 6, 56),
+        (7, 2, 57), (8, 2, 57), (9, 2, 57), (10, 2, 57),
+        (11, 2, 57), (12, 2, 57), (13, 2, 57), (14, 6, 57),
+    ],
+    /*  37 */ [
+        (7, 2, 61), (8, 2, 61), (9, 2, 61), (10, 2, 61),
+        (11, 2, 61), (12, 2, 61), (13, 2, 61), (14, 6, 61),
+        (7, 2, 65), (8, 2, 65), (9, 2, 65), (10, 2, 65),
+        (11, 2, 65), (12, 2, 65), (13, 2, 65), (14, 6, 65),
+    ],
+    /*  38 */ [
+        (7, 2, 95), (8, 2, 95), (9, 2, 95), (10, 2, 95),
+        (11, 2, 95), (12, 2, 95), (13, 2, 95), (14, 6, 95),
+        (7, 2, 98), (8, 2, 98), (9, 2, 98), (10, 2, 98),
+        (11, 2, 98), (12, 2, 98), (13, 2, 98), (14, 6, 98),
+    ],
+    /*  39 */ [
+        (7, 2, 100), (8, 2, 100), (9, 2, 100), (10, 2, 100),
+        (11, 2, 100), (12, 2, 100), (13, 2, 100), (14, 6, 100),
+        (7, 2, 102), (8, 2, 102), (9, 2, 102), (10, 2, 102),
+        (11, 2, 102), (12, 2, 102), (13, 2, 102), (14, 6, 102),
+    ],
+    /*  40 */ [
+        (7, 2, 103), (8, 2, 103), (9, 2, 103), (10, 2, 103),
+        (11, 2, 103), (12, 2, 103), (13, 2, 103), (14, 6, 103),
+        (7, 2, 104), (8, 2, 104), (9, 2, 104), (10, 2, 104),
+        (11, 2, 104), (12, 2, 104), (13, 2, 104), (14, 6, 104),
+    ],
+    /*  41 */ [
+        (7, 2, 108), (8, 2, 108), (9, 2, 108), (10, 2, 108),
+        (11, 2, 108), (12, 2, 108), (13, 2, 108), (14, 6, 108),
+        (7, 2, 109), (8, 2, 109), (9, 2, 109), (10, 2, 109),
+        (11, 2, 109), (12, 2, 109), (13, 2, 109), (14, 6, 109),
+    ],
+    /*  42 */ [
+        (7, 2, 110), (8, 2, 110), (9, 2, 110), (10, 2, 110),
+        (11, 2, 110), (12, 2, 110), (13, 2, 110), (14, 6, 110),
+        (7, 2, 112), (8, 2, 112), (9, 2, 112), (10, 2, 112),
+        (11, 2, 112), (12, 2, 112), (13, 2, 112), (14, 6, 112),
+    ],
+    /*  43 */ [
+        (7, 2, 114), (8, 2, 114), (9, 2, 114), (10, 2, 114),
+        (11, 2, 114), (12, 2, 114), (13, 2, 114), (14, 6, 114),
+        (7, 2, 117), (8, 2, 117), (9, 2, 117), (10, 2, 117),
+        (11, 2, 117), (12, 2, 117), (13, 2, 117), (14, 6, 117),
+    ],
+    /*  44 */ [
+        (3, 2, 58), (4, 2, 58), (5, 2, 58), (6, 6, 58),
+        (3, 2, 66), (4, 2, 66), (5, 2, 66), (6, 6, 66),
+        (3, 2, 67), (4, 2, 67), (5, 2, 67), (6, 6, 67),
+        (3, 2, 68), (4, 2, 68), (5, 2, 68), (6, 6, 68),
+    ],
+    /*  45 */ [
+        (3, 2, 69), (4, 2, 69), (5, 2, 69), (6, 6, 69),
+        (3, 2, 70), (4, 2, 70), (5, 2, 70), (6, 6, 70),
+        (3, 2, 71), (4, 2, 71), (5, 2, 71), (6, 6, 71),
+        (3, 2, 72), (4, 2, 72), (5, 2, 72), (6, 6, 72),
+    ],
+    /*  46 */ [
+        (3, 2, 73), (4, 2, 73), (5, 2, 73), (6, 6, 73),
+        (3, 2, 74), (4, 2, 74), (5, 2, 74), (6, 6, 74),
+        (3, 2, 75), (4, 2, 75), (5, 2, 75), (6, 6, 75),
+        (3, 2, 76), (4, 2, 76), (5, 2, 76), (6, 6, 76),
+    ],
+    /*  47 */ [
+        (3, 2, 77), (4, 2, 77), (5, 2, 77), (6, 6, 77),
+        (3, 2, 78), (4, 2, 78), (5, 2, 78), (6, 6, 78),
+        (3, 2, 79), (4, 2, 79), (5, 2, 79), (6, 6, 79),
+        (3, 2, 80), (4, 2, 80), (5, 2, 80), (6, 6, 80),
+    ],
+    /*  48 */ [
+        (3, 2, 81), (4, 2, 81), (5, 2, 81), (6, 6, 81),
+        (3, 2, 82), (4, 2, 82), (5, 2, 82), (6, 6, 82),
+        (3, 2, 83), (4, 2, 83), (5, 2, 83), (6, 6, 83),
+        (3, 2, 84), (4, 2, 84), (5, 2, 84), (6, 6, 84),
+    ],
+    /*  49 */ [
+        (3, 2, 85), (4, 2, 85), (5, 2, 85), (6, 6, 85),
+        (3, 2, 86), (4, 2, 86), (5, 2, 86), (6, 6, 86),
+        (3, 2, 87), (4, 2, 87), (5, 2, 87), (6, 6, 87),
+        (3, 2, 89), (4, 2, 89), (5, 2, 89), (6, 6, 89),
+    ],
+    /*  50 */ [
+        (3, 2, 106), (4, 2, 106), (5, 2, 106), (6, 6, 106),
+        (3, 2, 107), (4, 2, 107), (5, 2, 107), (6, 6, 107),
+        (3, 2, 113), (4, 2, 113), (5, 2, 113), (6, 6, 113),
+        (3, 2, 118), (4, 2, 118), (5, 2, 118), (6, 6, 118),
+    ],
+    /*  51 */ [
+        (3, 2, 119), (4, 2, 119), (5, 2, 119), (6, 6, 119),
+        (3, 2, 120), (4, 2, 120), (5, 2, 120), (6, 6, 120),
+        (3, 2, 121), (4, 2, 121), (5, 2, 121), (6, 6, 121),
+        (3, 2, 122), (4, 2, 122), (5, 2, 122), (6, 6, 122),
+    ],
+    /*  52 */ [
+        (1, 2, 38), (2, 6, 38), (1, 2, 42), (2, 6, 42),
+        (1, 2, 44), (2, 6, 44), (1, 2, 59), (2, 6, 59),
+        (1, 2, 88), (2, 6, 88), (1, 2, 90), (2, 6, 90),
+        (77, 0, 0), (78, 0, 0), (79, 0, 0), (80, 0, 0),
+    ],
+    /*  53 */ [
+        (7, 2, 58), (8, 2, 58), (9, 2, 58), (10, 2, 58),
+        (11, 2, 58), (12, 2, 58), (13, 2, 58), (14, 6, 58),
+        (7, 2, 66), (8, 2, 66), (9, 2, 66), (10, 2, 66),
+        (11, 2, 66), (12, 2, 66), (13, 2, 66), (14, 6, 66),
+    ],
+    /*  54 */ [
+        (7, 2, 67), (8, 2, 67), (9, 2, 67), (10, 2, 67),
+        (11, 2, 67), (12, 2, 67), (13, 2, 67), (14, 6, 67),
+        (7, 2, 68), (8, 2, 68), (9, 2, 68), (10, 2, 68),
+        (11, 2, 68), (12, 2, 68), (13, 2, 68), (14, 6, 68),
+    ],
+    /*  55 */ [
+        (7, 2, 69), (8, 2, 69), (9, 2, 69), (10, 2, 69),
+        (11, 2, 69), (12, 2, 69), (13, 2, 69), (14, 6, 69),
+        (7, 2, 70), (8, 2, 70), (9, 2, 70), (10, 2, 70),
+        (11, 2, 70), (12, 2, 70), (13, 2, 70), (14, 6, 70),
+    ],
+    /*  56 */ [
+        (7, 2, 71), (8, 2, 71), (9, 2, 71), (10, 2, 71),
+        (11, 2, 71), (12, 2, 71), (13, 2, 71), (14, 6, 71),
+        (7, 2, 72), (8, 2, 72), (9, 2, 72), (10, 2, 72),
+        (11, 2, 72), (12, 2, 72), (13, 2, 72), (14, 6, 72),
+    ],
+    /*  57 */ [
+        (7, 2, 73), (8, 2, 73), (9, 2, 73), (10, 2, 73),
+        (11, 2, 73), (12, 2, 73), (13, 2, 73), (14, 6, 73),
+        (7, 2, 74), (8, 2, 74), (9, 2, 74), (10, 2, 74),
+        (11, 2, 74), (12, 2, 74), (13, 2, 74), (14, 6, 74),
+    ],
+    /*  58 */ [
+        (7, 2, 75), (8, 2, 75), (9, 2, 75), (10, 2, 75),
+        (11, 2, 75), (12, 2, 75), (13, 2, 75), (14, 6, 75),
+        (7, 2, 76), (8, 2, 76), (9, 2, 76), (10, 2, 76),
+        (11, 2, 76), (12, 2, 76), (13, 2, 76), (14, 6, 76),
+    ],
+    /*  59 */ [
+        (7, 2, 77), (8, 2, 77), (9, 2, 77), (10, 2, 77),
+        (11, 2, 77), (12, 2, 77), (13, 2, 77), (14, 6, 77),
+        (7, 2, 78), (8, 2, 78), (9, 2, 78), (10, 2, 78),
+        (11, 2, 78), (12, 2, 78), (13, 2, 78), (14, 6, 78),
+    ],
+    /*  60 */ [
+        (7, 2, 79), (8, 2, 79), (9, 2, 79), (10, 2, 79),
+        (11, 2, 79), (12, 2, 79), (13, 2, 79), (14, 6, 79),
+        (7, 2, 80), (8, 2, 80), (9, 2, 80), (10, 2, 80),
+        (11, 2, 80), (12, 2, 80), (13, 2, 80), (14, 6, 80),
+    ],
+    /*  61 */ [
+        (7, 2, 81), (8, 2, 81), (9, 2, 81), (10, 2, 81),
+        (11, 2, 81), (12, 2, 81), (13, 2, 81), (14, 6, 81),
+        (7, 2, 82), (8, 2, 82), (9, 2, 82), (10, 2, 82),
+        (11, 2, 82), (12, 2, 82), (13, 2, 82), (14, 6, 82),
+    ],
+    /*  62 */ [
+        (7, 2, 83), (8, 2, 83), (9, 2, 83), (10, 2, 83),
+        (11, 2, 83), (12, 2, 83), (13, 2, 83), (14, 6, 83),
+        (7, 2, 84), (8, 2, 84), (9, 2, 84), (10, 2, 84),
+        (11, 2, 84), (12, 2, 84), (13, 2, 84), (14, 6, 84),
+    ],
+    /*  63 */ [
+        (7, 2, 85), (8, 2, 85), (9, 2, 85), (10, 2, 85),
+        (11, 2, 85), (12, 2, 85), (13, 2, 85), (14, 6, 85),
+        (7, 2, 86), (8, 2, 86), (9, 2, 86), (10, 2, 86),
+        (11, 2, 86), (12, 2, 86), (13, 2, 86), (14, 6, 86),
+    ],
+    /*  64 */ [
+        (7, 2, 87), (8, 2, 87), (9, 2, 87), (10, 2, 87),
+        (11, 2, 87), (12, 2, 87), (13, 2, 87), (14, 6, 87),
+        (7, 2, 89), (8, 2, 89), (9, 2, 89), (10, 2, 89),
+        (11, 2, 89), (12, 2, 89), (13, 2, 89), (14, 6, 89),
+    ],
+    /*  65 */ [
+        (7, 2, 106), (8, 2, 106), (9, 2, 106), (10, 2, 106),
+        (11, 2, 106), (12, 2, 106), (13, 2, 106), (14, 6, 106),
+        (7, 2, 107), (8, 2, 107), (9, 2, 107), (10, 2, 107),
+        (11, 2, 107), (12, 2, 107), (13, 2, 107), (14, 6, 107),
+    ],
+    /*  66 */ [
+        (7, 2, 113), (8, 2, 113), (9, 2, 113), (10, 2, 113),
+        (11, 2, 113), (12, 2, 113), (13, 2, 113), (14, 6, 113),
+        (7, 2, 118), (8, 2, 118), (9, 2, 118), (10, 2, 118),
+        (11, 2, 118), (12, 2, 118), (13, 2, 118), (14, 6, 118),
+    ],
+    /*  67 */ [
+        (7, 2, 119), (8, 2, 119), (9, 2, 119), (10, 2, 119),
+        (11, 2, 119), (12, 2, 119), (13, 2, 119), (14, 6, 119),
+        (7, 2, 120), (8, 2, 120), (9, 2, 120), (10, 2, 120),
+        (11, 2, 120), (12, 2, 120), (13, 2, 120), (14, 6, 120),
+    ],
+    /*  68 */ [
+        (7, 2, 121), (8, 2, 121), (9, 2, 121), (10, 2, 121),
+        (11, 2, 121), (12, 2, 121), (13, 2, 121), (14, 6, 121),
+        (7, 2, 122), (8, 2, 122), (9, 2, 122), (10, 2, 122),
+        (11, 2, 122), (12, 2, 122), (13, 2, 122), (14, 6, 122),
+    ],
+    /*  69 */ [
+        (3, 2, 38), (4, 2, 38), (5, 2, 38), (6, 6, 38),
+        (3, 2, 42), (4, 2, 42), (5, 2, 42), (6, 6, 42),
+        (3, 2, 44), (4, 2, 44), (5, 2, 44), (6, 6, 44),
+        (3, 2, 59), (4, 2, 59), (5, 2, 59), (6, 6, 59),
+    ],
+    /*  70 */ [
+        (3, 2, 88), (4, 2, 88), (5, 2, 88), (6, 6, 88),
+        (3, 2, 90), (4, 2, 90), (5, 2, 90), (6, 6, 90),
+        (0, 6, 33), (0, 6, 34), (0, 6, 40), (0, 6, 41),
+        (0, 6, 63), (81, 0, 0), (82, 0, 0), (83, 0, 0),
+    ],
+    /*  71 */ [
+        (7, 2, 38), (8, 2, 38), (9, 2, 38), (10, 2, 38),
+        (11, 2, 38), (12, 2, 38), (13, 2, 38), (14, 6, 38),
+        (7, 2, 42), (8, 2, 42), (9, 2, 42), (10, 2, 42),
+        (11, 2, 42), (12, 2, 42), (13, 2, 42), (14, 6, 42),
+    ],
+    /*  72 */ [
+        (7, 2, 44), (8, 2, 44), (9, 2, 44), (10, 2, 44),
+        (11, 2, 44), (12, 2, 44), (13, 2, 44), (14, 6, 44),
+        (7, 2, 59), (8, 2, 59), (9, 2, 59), (10, 2, 59),
+        (11, 2, 59), (12, 2, 59), (13, 2, 59), (14, 6, 59),
+    ],
+    /*  73 */ [
+        (7, 2, 88), (8, 2, 88), (9, 2, 88), (10, 2, 88),
+        (11, 2, 88), (12, 2, 88), (13, 2, 88), (14, 6, 88),
+        (7, 2, 90), (8, 2, 90), (9, 2, 90), (10, 2, 90),
+        (11, 2, 90), (12, 2, 90), (13, 2, 90), (14, 6, 90),
+    ],
+    /*  74 */ [
+        (1, 2, 33), (2, 6, 33), (1, 2, 34), (2, 6, 34),
+        (1, 2, 40), (2, 6, 40), (1, 2, 41), (2, 6, 41),
+        (1, 2, 63), (2, 6, 63), (0, 6, 39), (0, 6, 43),
+        (0, 6, 124), (84, 0, 0), (85, 0, 0), (86, 0, 0),
+    ],
+    /*  75 */ [
+        (3, 2, 33), (4, 2, 33), (5, 2, 33), (6, 6, 33),
+        (3, 2, 34), (4, 2, 34), (5, 2, 34), (6, 6, 34),
+        (3, 2, 40), (4, 2, 40), (5, 2, 40), (6, 6, 40),
+        (3, 2, 41), (4, 2, 41), (5, 2, 41), (6, 6, 41),
+    ],
+    /*  76 */ [
+        (3, 2, 63), (4, 2, 63), (5, 2, 63), (6, 6, 63),
+        (1, 2, 39), (2, 6, 39), (1, 2, 43), (2, 6, 43),
+        (1, 2, 124), (2, 6, 124), (0, 6, 35), (0, 6, 62),
+        (87, 0, 0), (88, 0, 0), (89, 0, 0), (90, 0, 0),
+    ],
+    /*  77 */ [
+        (7, 2, 33), (8, 2, 33), (9, 2, 33), (10, 2, 33),
+        (11, 2, 33), (12, 2, 33), (13, 2, 33), (14, 6, 33),
+        (7, 2, 34), (8, 2, 34), (9, 2, 34), (10, 2, 34),
+        (11, 2, 34), (12, 2, 34), (13, 2, 34), (14, 6, 34),
+    ],
+    /*  78 */ [
+        (7, 2, 40), (8, 2, 40), (9, 2, 40), (10, 2, 40),
+        (11, 2, 40), (12, 2, 40), (13, 2, 40), (14, 6, 40),
+        (7, 2, 41), (8, 2, 41), (9, 2, 41), (10, 2, 41),
+        (11, 2, 41), (12, 2, 41), (13, 2, 41), (14, 6, 41),
+    ],
+    /*  79 */ [
+        (7, 2, 63), (8, 2, 63), (9, 2, 63), (10, 2, 63),
+        (11, 2, 63), (12, 2, 63), (13, 2, 63), (14, 6, 63),
+        (3, 2, 39), (4, 2, 39), (5, 2, 39), (6, 6, 39),
+        (3, 2, 43), (4, 2, 43), (5, 2, 43), (6, 6, 43),
+    ],
+    /*  80 */ [
+        (3, 2, 124), (4, 2, 124), (5, 2, 124), (6, 6, 124),
+        (1, 2, 35), (2, 6, 35), (1, 2, 62), (2, 6, 62),
+        (0, 6, 0), (0, 6, 36), (0, 6, 64), (0, 6, 91),
+        (0, 6, 93), (0, 6, 126), (91, 0, 0), (92, 0, 0),
+    ],
+    /*  81 */ [
+        (7, 2, 39), (8, 2, 39), (9, 2, 39), (10, 2, 39),
+        (11, 2, 39), (12, 2, 39), (13, 2, 39), (14, 6, 39),
+        (7, 2, 43), (8, 2, 43), (9, 2, 43), (10, 2, 43),
+        (11, 2, 43), (12, 2, 43), (13, 2, 43), (14, 6, 43),
+    ],
+    /*  82 */ [
+        (7, 2, 124), (8, 2, 124), (9, 2, 124), (10, 2, 124),
+        (11, 2, 124), (12, 2, 124), (13, 2, 124), (14, 6, 124),
+        (3, 2, 35), (4, 2, 35), (5, 2, 35), (6, 6, 35),
+        (3, 2, 62), (4, 2, 62), (5, 2, 62), (6, 6, 62),
+    ],
+    /*  83 */ [
+        (1, 2, 0), (2, 6, 0), (1, 2, 36), (2, 6, 36),
+        (1, 2, 64), (2, 6, 64), (1, 2, 91), (2, 6, 91),
+        (1, 2, 93), (2, 6, 93), (1, 2, 126), (2, 6, 126),
+        (0, 6, 94), (0, 6, 125), (93, 0, 0), (94, 0, 0),
+    ],
+    /*  84 */ [
+        (7, 2, 35), (8, 2, 35), (9, 2, 35), (10, 2, 35),
+        (11, 2, 35), (12, 2, 35), (13, 2, 35), (14, 6, 35),
+        (7, 2, 62), (8, 2, 62), (9, 2, 62), (10, 2, 62),
+        (11, 2, 62), (12, 2, 62), (13, 2, 62), (14, 6, 62),
+    ],
+    /*  85 */ [
+        (3, 2, 0), (4, 2, 0), (5, 2, 0), (6, 6, 0),
+        (3, 2, 36), (4, 2, 36), (5, 2, 36), (6, 6, 36),
+        (3, 2, 64), (4, 2, 64), (5, 2, 64), (6, 6, 64),
+        (3, 2, 91), (4, 2, 91), (5, 2, 91), (6, 6, 91),
+    ],
+    /*  86 */ [
+        (3, 2, 93), (4, 2, 93), (5, 2, 93), (6, 6, 93),
+        (3, 2, 126), (4, 2, 126), (5, 2, 126), (6, 6, 126),
+        (1, 2, 94), (2, 6, 94), (1, 2, 125), (2, 6, 125),
+        (0, 6, 60), (0, 6, 96), (0, 6, 123), (95, 0, 0),
+    ],
+    /*  87 */ [
+        (7, 2, 0), (8, 2, 0), (9, 2, 0), (10, 2, 0),
+        (11, 2, 0), (12, 2, 0), (13, 2, 0), (14, 6, 0),
+        (7, 2, 36), (8, 2, 36), (9, 2, 36), (10, 2, 36),
+        (11, 2, 36), (12, 2, 36), (13, 2, 36), (14, 6, 36),
+    ],
+    /*  88 */ [
+        (7, 2, 64), (8, 2, 64), (9, 2, 64), (10, 2, 64),
+        (11, 2, 64), (12, 2, 64), (13, 2, 64), (14, 6, 64),
+        (7, 2, 91), (8, 2, 91), (9, 2, 91), (10, 2, 91),
+        (11, 2, 91), (12, 2, 91), (13, 2, 91), (14, 6, 91),
+    ],
+    /*  89 */ [
+        (7, 2, 93), (8, 2, 93), (9, 2, 93), (10, 2, 93),
+        (11, 2, 93), (12, 2, 93), (13, 2, 93), (14, 6, 93),
+        (7, 2, 126), (8, 2, 126), (9, 2, 126), (10, 2, 126),
+        (11, 2, 126), (12, 2, 126), (13, 2, 126), (14, 6, 126),
+    ],
+    /*  90 */ [
+        (3, 2, 94), (4, 2, 94), (5, 2, 94), (6, 6, 94),
+        (3, 2, 125), (4, 2, 125), (5, 2, 125), (6, 6, 125),
+        (1, 2, 60), (2, 6, 60), (1, 2, 96), (2, 6, 96),
+        (1, 2, 123), (2, 6, 123), (96, 0, 0), (97, 0, 0),
+    ],
+    /*  91 */ [
+        (7, 2, 94), (8, 2, 94), (9, 2, 94), (10, 2, 94),
+        (11, 2, 94), (12, 2, 94), (13, 2, 94), (14, 6, 94),
+        (7, 2, 125), (8, 2, 125), (9, 2, 125), (10, 2, 125),
+        (11, 2, 125), (12, 2, 125), (13, 2, 125), (14, 6, 125),
+    ],
+    /*  92 */ [
+        (3, 2, 60), (4, 2, 60), (5, 2, 60), (6, 6, 60),
+        (3, 2, 96), (4, 2, 96), (5, 2, 96), (6, 6, 96),
+        (3, 2, 123), (4, 2, 123), (5, 2, 123), (6, 6, 123),
+        (98, 0, 0), (99, 0, 0), (100, 0, 0), (101, 0, 0),
+    ],
+    /*  93 */ [
+        (7, 2, 60), (8, 2, 60), (9, 2, 60), (10, 2, 60),
+        (11, 2, 60), (12, 2, 60), (13, 2, 60), (14, 6, 60),
+        (7, 2, 96), (8, 2, 96), (9, 2, 96), (10, 2, 96),
+        (11, 2, 96), (12, 2, 96), (13, 2, 96), (14, 6, 96),
+    ],
+    /*  94 */ [
+        (7, 2, 123), (8, 2, 123), (9, 2, 123), (10, 2, 123),
+        (11, 2, 123), (12, 2, 123), (13, 2, 123), (14, 6, 123),
+        (102, 0, 0), (103, 0, 0), (104, 0, 0), (105, 0, 0),
+        (106, 0, 0), (107, 0, 0), (108, 0, 0), (109, 0, 0),
+    ],
+    /*  95 */ [
+        (0, 6, 92), (0, 6, 195), (0, 6, 208), (110, 0, 0),
+        (111, 0, 0), (112, 0, 0), (113, 0, 0), (114, 0, 0),
+        (115, 0, 0), (116, 0, 0), (117, 0, 0), (118, 0, 0),
+        (119, 0, 0), (120, 0, 0), (121, 0, 0), (122, 0, 0),
+    ],
+    /*  96 */ [
+        (1, 2, 92), (2, 6, 92), (1, 2, 195), (2, 6, 195),
+        (1, 2, 208), (2, 6, 208), (0, 6, 128), (0, 6, 130),
+        (0, 6, 131), (0, 6, 162), (0, 6, 184), (0, 6, 194),
+        (0, 6, 224), (0, 6, 226), (123, 0, 0), (124, 0, 0),
+    ],
+    /*  97 */ [
+        (125, 0, 0), (126, 0, 0), (127, 0, 0), (128, 0, 0),
+        (129, 0, 0), (130, 0, 0), (131, 0, 0), (132, 0, 0),
+        (133, 0, 0), (134, 0, 0), (135, 0, 0), (136, 0, 0),
+        (137, 0, 0), (138, 0, 0), (139, 0, 0), (140, 0, 0),
+    ],
+    /*  98 */ [
+        (3, 2, 92), (4, 2, 92), (5, 2, 92), (6, 6, 92),
+        (3, 2, 195), (4, 2, 195), (5, 2, 195), (6, 6, 195),
+        (3, 2, 208), (4, 2, 208), (5, 2, 208), (6, 6, 208),
+        (1, 2, 128), (2, 6, 128), (1, 2, 130), (2, 6, 130),
+    ],
+    /*  99 */ [
+        (1, 2, 131), (2, 6, 131), (1, 2, 162), (2, 6, 162),
+        (1, 2, 184), (2, 6, 184), (1, 2, 194), (2, 6, 194),
+        (1, 2, 224), (2, 6, 224), (1, 2, 226), (2, 6, 226),
+        (0, 6, 153), (0, 6, 161), (0, 6, 167), (0, 6, 172),
+    ],
+    /* 100 */ [
+        (0, 6, 176), (0, 6, 177), (0, 6, 179), (0, 6, 209),
+        (0, 6, 216), (0, 6, 217), (0, 6, 227), (0, 6, 229),
+        (0, 6, 230), (141, 0, 0), (142, 0, 0), (143, 0, 0),
+        (144, 0, 0), (145, 0, 0), (146, 0, 0), (147, 0, 0),
+    ],
+    /* 101 */ [
+        (148, 0, 0), (149, 0, 0), (150, 0, 0), (151, 0, 0),
+        (152, 0, 0), (153, 0, 0), (154, 0, 0), (155, 0, 0),
+        (156, 0, 0), (157, 0, 0), (158, 0, 0), (159, 0, 0),
+        (160, 0, 0), (161, 0, 0), (162, 0, 0), (163, 0, 0),
+    ],
+    /* 102 */ [
+        (7, 2, 92), (8, 2, 92), (9, 2, 92), (10, 2, 92),
+        (11, 2, 92), (12, 2, 92), (13, 2, 92), (14, 6, 92),
+        (7, 2, 195), (8, 2, 195), (9, 2, 195), (10, 2, 195),
+        (11, 2, 195), (12, 2, 195), (13, 2, 195), (14, 6, 195),
+    ],
+    /* 103 */ [
+        (7, 2, 208), (8, 2, 208), (9, 2, 208), (10, 2, 208),
+        (11, 2, 208), (12, 2, 208), (13, 2, 208), (14, 6, 208),
+        (3, 2, 128), (4, 2, 128), (5, 2, 128), (6, 6, 128),
+        (3, 2, 130), (4, 2, 130), (5, 2, 130), (6, 6, 130),
+    ],
+    /* 104 */ [
+        (3, 2, 131), (4, 2, 131), (5, 2, 131), (6, 6, 131),
+        (3, 2, 162), (4, 2, 162), (5, 2, 162), (6, 6, 162),
+        (3, 2, 184), (4, 2, 184), (5, 2, 184), (6, 6, 184),
+        (3, 2, 194), (4, 2, 194), (5, 2, 194), (6, 6, 194),
+    ],
+    /* 105 */ [
+        (3, 2, 224), (4, 2, 224), (5, 2, 224), (6, 6, 224),
+        (3, 2, 226), (4, 2, 226), (5, 2, 226), (6, 6, 226),
+        (1, 2, 153), (2, 6, 153), (1, 2, 161), (2, 6, 161),
+        (1, 2, 167), (2, 6, 167), (1, 2, 172), (2, 6, 172),
+    ],
+    /* 106 */ [
+        (1, 2, 176), (2, 6, 176), (1, 2, 177), (2, 6, 177),
+        (1, 2, 179), (2, 6, 179), (1, 2, 209), (2, 6, 209),
+        (1, 2, 216), (2, 6, 216), (1, 2, 217), (2, 6, 217),
+        (1, 2, 227), (2, 6, 227), (1, 2, 229), (2, 6, 229),
+    ],
+    /* 107 */ [
+        (1, 2, 230), (2, 6, 230), (0, 6, 129), (0, 6, 132),
+        (0, 6, 133), (0, 6, 134), (0, 6, 136), (0, 6, 146),
+        (0, 6, 154), (0, 6, 156), (0, 6, 160), (0, 6, 163),
+        (0, 6, 164), (0, 6, 169), (0, 6, 170), (0, 6, 173),
+    ],
+    /* 108 */ [
+        (0, 6, 178), (0, 6, 181), (0, 6, 185), (0, 6, 186),
+        (0, 6, 187), (0, 6, 189), (0, 6, 190), (0, 6, 196),
+        (0, 6, 198), (0, 6, 228), (0, 6, 232), (0, 6, 233),
+        (164, 0, 0), (165, 0, 0), (166, 0, 0), (167, 0, 0),
+    ],
+    /* 109 */ [
+        (168, 0, 0), (169, 0, 0), (170, 0, 0), (171, 0, 0),
+        (172, 0, 0), (173, 0, 0), (174, 0, 0), (175, 0, 0),
+        (176, 0, 0), (177, 0, 0), (178, 0, 0), (179, 0, 0),
+        (180, 0, 0), (181, 0, 0), (182, 0, 0), (183, 0, 0),
+    ],
+    /* 110 */ [
+        (7, 2, 128), (8, 2, 128), (9, 2, 128), (10, 2, 128),
+        (11, 2, 128), (12, 2, 128), (13, 2, 128), (14, 6, 128),
+        (7, 2, 130), (8, 2, 130), (9, 2, 130), (10, 2, 130),
+        (11, 2, 130), (12, 2, 130), (13, 2, 130), (14, 6, 130),
+    ],
+    /* 111 */ [
+        (7, 2, 131), (8, 2, 131), (9, 2, 131), (10, 2, 131),
+        (11, 2, 131), (12, 2, 131), (13, 2, 131), (14, 6, 131),
+        (7, 2, 162), (8, 2, 162), (9, 2, 162), (10, 2, 162),
+        (11, 2, 162), (12, 2, 162), (13, 2, 162), (14, 6, 162),
+    ],
+    /* 112 */ [
+        (7, 2, 184), (8, 2, 184), (9, 2, 184), (10, 2, 184),
+        (11, 2, 184), (12, 2, 184), (13, 2, 184), (14, 6, 184),
+        (7, 2, 194), (8, 2, 194), (9, 2, 194), (10, 2, 194),
+        (11, 2, 194), (12, 2, 194), (13, 2, 194), (14, 6, 194),
+    ],
+    /* 113 */ [
+        (7, 2, 224), (8, 2, 224), (9, 2, 224), (10, 2, 224),
+        (11, 2, 224), (12, 2, 224), (13, 2, 224), (14, 6, 224),
+        (7, 2, 226), (8, 2, 226), (9, 2, 226), (10, 2, 226),
+        (11, 2, 226), (12, 2, 226), (13, 2, 226), (14, 6, 226),
+    ],
+    /* 114 */ [
+        (3, 2, 153), (4, 2, 153), (5, 2, 153), (6, 6, 153),
+        (3, 2, 161), (4, 2, 161), (5, 2, 161), (6, 6, 161),
+        (3, 2, 167), (4, 2, 167), (5, 2, 167), (6, 6, 167),
+        (3, 2, 172), (4, 2, 172), (5, 2, 172), (6, 6, 172),
+    ],
+    /* 115 */ [
+        (3, 2, 176), (4, 2, 176), (5, 2, 176), (6, 6, 176),
+        (3, 2, 177), (4, 2, 177), (5, 2, 177), (6, 6, 177),
+        (3, 2, 179), (4, 2, 179), (5, 2, 179), (6, 6, 179),
+        (3, 2, 209), (4, 2, 209), (5, 2, 209), (6, 6, 209),
+    ],
+    /* 116 */ [
+        (3, 2, 216), (4, 2, 216), (5, 2, 216), (6, 6, 216),
+        (3, 2, 217), (4, 2, 217), (5, 2, 217), (6, 6, 217),
+        (3, 2, 227), (4, 2, 227), (5, 2, 227), (6, 6, 227),
+        (3, 2, 229), (4, 2, 229), (5, 2, 229), (6, 6, 229),
+    ],
+    /* 117 */ [
+        (3, 2, 230), (4, 2, 230), (5, 2, 230), (6, 6, 230),
+        (1, 2, 129), (2, 6, 129), (1, 2, 132), (2, 6, 132),
+        (1, 2, 133), (2, 6, 133), (1, 2, 134), (2, 6, 134),
+        (1, 2, 136), (2, 6, 136), (1, 2, 146), (2, 6, 146),
+    ],
+    /* 118 */ [
+        (1, 2, 154), (2, 6, 154), (1, 2, 156), (2, 6, 156),
+        (1, 2, 160), (2, 6, 160), (1, 2, 163), (2, 6, 163),
+        (1, 2, 164), (2, 6, 164), (1, 2, 169), (2, 6, 169),
+        (1, 2, 170), (2, 6, 170), (1, 2, 173), (2, 6, 173),
+    ],
+    /* 119 */ [
+        (1, 2, 178), (2, 6, 178), (1, 2, 181), (2, 6, 181),
+        (1, 2, 185), (2, 6, 185), (1, 2, 186), (2, 6, 186),
+        (1, 2, 187), (2, 6, 187), (1, 2, 189), (2, 6, 189),
+        (1, 2, 190), (2, 6, 190), (1, 2, 196), (2, 6, 196),
+    ],
+    /* 120 */ [
+        (1, 2, 198), (2, 6, 198), (1, 2, 228), (2, 6, 228),
+        (1, 2, 232), (2, 6, 232), (1, 2, 233), (2, 6, 233),
+        (0, 6, 1), (0, 6, 135), (0, 6, 137), (0, 6, 138),
+        (0, 6, 139), (0, 6, 140), (0, 6, 141), (0, 6, 143),
+    ],
+    /* 121 */ [
+        (0, 6, 147), (0, 6, 149), (0, 6, 150), (0, 6, 151),
+        (0, 6, 152), (0, 6, 155), (0, 6, 157), (0, 6, 158),
+        (0, 6, 165), (0, 6, 166), (0, 6, 168), (0, 6, 174),
+        (0, 6, 175), (0, 6, 180), (0, 6, 182), (0, 6, 183),
+    ],
+    /* 122 */ [
+        (0, 6, 188), (0, 6, 191), (0, 6, 197), (0, 6, 231),
+        (0, 6, 239), (184, 0, 0), (185, 0, 0), (186, 0, 0),
+        (187, 0, 0), (188, 0, 0), (189, 0, 0), (190, 0, 0),
+        (191, 0, 0), (192, 0, 0), (193, 0, 0), (194, 0, 0),
+    ],
+    /* 123 */ [
+        (7, 2, 153), (8, 2, 153), (9, 2, 153), (10, 2, 153),
+        (11, 2, 153), (12, 2, 153), (13, 2, 153), (14, 6, 153),
+        (7, 2, 161), (8, 2, 161), (9, 2, 161), (10, 2, 161),
+        (11, 2, 161), (12, 2, 161), (13, 2, 161), (14, 6, 161),
+    ],
+    /* 124 */ [
+        (7, 2, 167), (8, 2, 167), (9, 2, 167), (10, 2, 167),
+        (11, 2, 167), (12, 2, 167), (13, 2, 167), (14, 6, 167),
+        (7, 2, 172), (8, 2, 172), (9, 2, 172), (10, 2, 172),
+        (11, 2, 172), (12, 2, 172), (13, 2, 172), (14, 6, 172),
+    ],
+    /* 125 */ [
+        (7, 2, 176), (8, 2, 176), (9, 2, 176), (10, 2, 176),
+        (11, 2, 176), (12, 2, 176), (13, 2, 176), (14, 6, 176),
+        (7, 2, 177), (8, 2, 177), (9, 2, 177), (10, 2, 177),
+        (11, 2, 177), (12, 2, 177), (13, 2, 177), (14, 6, 177),
+    ],
+    /* 126 */ [
+        (7, 2, 179), (8, 2, 179), (9, 2, 179), (10, 2, 179),
+        (11, 2, 179), (12, 2, 179), (13, 2, 179), (14, 6, 179),
+        (7, 2, 209), (8, 2, 209), (9, 2, 209), (10, 2, 209),
+        (11, 2, 209), (12, 2, 209), (13, 2, 209), (14, 6, 209),
+    ],
+    /* 127 */ [
+        (7, 2, 216), (8, 2, 216), (9, 2, 216), (10, 2, 216),
+        (11, 2, 216), (12, 2, 216), (13, 2, 216), (14, 6, 216),
+        (7, 2, 217), (8, 2, 217), (9, 2, 217), (10, 2, 217),
+        (11, 2, 217), (12, 2, 217), (13, 2, 217), (14, 6, 217),
+    ],
+    /* 128 */ [
+        (7, 2, 227), (8, 2, 227), (9, 2, 227), (10, 2, 227),
+        (11, 2, 227), (12, 2, 227), (13, 2, 227), (14, 6, 227),
+        (7, 2, 229), (8, 2, 229), (9, 2, 229), (10, 2, 229),
+        (11, 2, 229), (12, 2, 229), (13, 2, 229), (14, 6, 229),
+    ],
+    /* 129 */ [
+        (7, 2, 230), (8, 2, 230), (9, 2, 230), (10, 2, 230),
+        (11, 2, 230), (12, 2, 230), (13, 2, 230), (14, 6, 230),
+        (3, 2, 129), (4, 2, 129), (5, 2, 129), (6, 6, 129),
+        (3, 2, 132), (4, 2, 132), (5, 2, 132), (6, 6, 132),
+    ],
+    /* 130 */ [
+        (3, 2, 133), (4, 2, 133), (5, 2, 133), (6, 6, 133),
+        (3, 2, 134), (4, 2, 134), (5, 2, 134), (6, 6, 134),
+        (3, 2, 136), (4, 2, 136), (5, 2, 136), (6, 6, 136),
+        (3, 2, 146), (4, 2, 146), (5, 2, 146), (6, 6, 146),
+    ],
+    /* 131 */ [
+        (3, 2, 154), (4, 2, 154), (5, 2, 154), (6, 6, 154),
+        (3, 2, 156), (4, 2, 156), (5, 2, 156), (6, 6, 156),
+        (3, 2, 160), (4, 2, 160), (5, 2, 160), (6, 6, 160),
+        (3, 2, 163), (4, 2, 163), (5, 2, 163), (6, 6, 163),
+    ],
+    /* 132 */ [
+        (3, 2, 164), (4, 2, 164), (5, 2, 164), (6, 6, 164),
+        (3, 2, 169), (4, 2, 169), (5, 2, 169), (6, 6, 169),
+        (3, 2, 170), (4, 2, 170), (5, 2, 170), (6, 6, 170),
+        (3, 2, 173), (4, 2, 173), (5, 2, 173), (6, 6, 173),
+    ],
+    /* 133 */ [
+        (3, 2, 178), (4, 2, 178), (5, 2, 178), (6, 6, 178),
+        (3, 2, 181), (4, 2, 181), (5, 2, 181), (6, 6, 181),
+        (3, 2, 185), (4, 2, 185), (5, 2, 185), (6, 6, 185),
+        (3, 2, 186), (4, 2, 186), (5, 2, 186), (6, 6, 186),
+    ],
+    /* 134 */ [
+        (3, 2, 187), (4, 2, 187), (5, 2, 187), (6, 6, 187),
+        (3, 2, 189), (4, 2, 189), (5, 2, 189), (6, 6, 189),
+        (3, 2, 190), (4, 2, 190), (5, 2, 190), (6, 6, 190),
+        (3, 2, 196), (4, 2, 196), (5, 2, 196), (6, 6, 196),
+    ],
+    /* 135 */ [
+        (3, 2, 198), (4, 2, 198), (5, 2, 198), (6, 6, 198),
+        (3, 2, 228), (4, 2, 228), (5, 2, 228), (6, 6, 228),
+        (3, 2, 232), (4, 2, 232), (5, 2, 232), (6, 6, 232),
+        (3, 2, 233), (4, 2, 233), (5, 2, 233), (6, 6, 233),
+    ],
+    /* 136 */ [
+        (1, 2, 1), (2, 6, 1), (1, 2, 135), (2, 6, 135),
+        (1, 2, 137), (2, 6, 137), (1, 2, 138), (2, 6, 138),
+        (1, 2, 139), (2, 6, 139), (1, 2, 140), (2, 6, 140),
+        (1, 2, 141), (2, 6, 141), (1, 2, 143), (2, 6, 143),
+    ],
+    /* 137 */ [
+        (1, 2, 147), (2, 6, 147), (1, 2, 149), (2, 6, 149),
+        (1, 2, 150), (2, 6, 150), (1, 2, 151), (2, 6, 151),
+        (1, 2, 152), (2, 6, 152), (1, 2, 155), (2, 6, 155),
+        (1, 2, 157), (2, 6, 157), (1, 2, 158), (2, 6, 158),
+    ],
+    /* 138 */ [
+        (1, 2, 165), (2, 6, 165), (1, 2, 166), (2, 6, 166),
+        (1, 2, 168), (2, 6, 168), (1, 2, 174), (2, 6, 174),
+        (1, 2, 175), (2, 6, 175), (1, 2, 180), (2, 6, 180),
+        (1, 2, 182), (2, 6, 182), (1, 2, 183), (2, 6, 183),
+    ],
+    /* 139 */ [
+        (1, 2, 188), (2, 6, 188), (1, 2, 191), (2, 6, 191),
+        (1, 2, 197), (2, 6, 197), (1, 2, 231), (2, 6, 231),
+        (1, 2, 239), (2, 6, 239), (0, 6, 9), (0, 6, 142),
+        (0, 6, 144), (0, 6, 145), (0, 6, 148), (0, 6, 159),
+    ],
+    /* 140 */ [
+        (0, 6, 171), (0, 6, 206), (0, 6, 215), (0, 6, 225),
+        (0, 6, 236), (0, 6, 237), (195, 0, 0), (196, 0, 0),
+        (197, 0, 0), (198, 0, 0), (199, 0, 0), (200, 0, 0),
+        (201, 0, 0), (202, 0, 0), (203, 0, 0), (204, 0, 0),
+    ],
+    /* 141 */ [
+        (7, 2, 129), (8, 2, 129), (9, 2, 129), (10, 2, 129),
+        (11, 2, 129), (12, 2, 129), (13, 2, 129), (14, 6, 129),
+        (7, 2, 132), (8, 2, 132), (9, 2, 132), (10, 2, 132),
+        (11, 2, 132), (12, 2, 132), (13, 2, 132), (14, 6, 132),
+    ],
+    /* 142 */ [
+        (7, 2, 133), (8, 2, 133), (9, 2, 133), (10, 2, 133),
+        (11, 2, 133), (12, 2, 133), (13, 2, 133), (14, 6, 133),
+        (7, 2, 134), (8, 2, 134), (9, 2, 134), (10, 2, 134),
+        (11, 2, 134), (12, 2, 134), (13, 2, 134), (14, 6, 134),
+    ],
+    /* 143 */ [
+        (7, 2, 136), (8, 2, 136), (9, 2, 136), (10, 2, 136),
+        (11, 2, 136), (12, 2, 136), (13, 2, 136), (14, 6, 136),
+        (7, 2, 146), (8, 2, 146), (9, 2, 146), (10, 2, 146),
+        (11, 2, 146), (12, 2, 146), (13, 2, 146), (14, 6, 146),
+    ],
+    /* 144 */ [
+        (7, 2, 154), (8, 2, 154), (9, 2, 154), (10, 2, 154),
+        (11, 2, 154), (12, 2, 154), (13, 2, 154), (14, 6, 154),
+        (7, 2, 156), (8, 2, 156), (9, 2, 156), (10, 2, 156),
+        (11, 2, 156), (12, 2, 156), (13, 2, 156), (14, 6, 156),
+    ],
+    /* 145 */ [
+        (7, 2, 160), (8, 2, 160), (9, 2, 160), (10, 2, 160),
+        (11, 2, 160), (12, 2, 160), (13, 2, 160), (14, 6, 160),
+        (7, 2, 163), (8, 2, 163), (9, 2, 163), (10, 2, 163),
+        (11, 2, 163), (12, 2, 163), (13, 2, 163), (14, 6, 163),
+    ],
+    /* 146 */ [
+        (7, 2, 164), (8, 2, 164), (9, 2, 164), (10, 2, 164),
+        (11, 2, 164), (12, 2, 164), (13, 2, 164), (14, 6, 164),
+        (7, 2, 169), (8, 2, 169), (9, 2, 169), (10, 2, 169),
+        (11, 2, 169), (12, 2, 169), (13, 2, 169), (14, 6, 169),
+    ],
+    /* 147 */ [
+        (7, 2, 170), (8, 2, 170), (9, 2, 170), (10, 2, 170),
+        (11, 2, 170), (12, 2, 170), (13, 2, 170), (14, 6, 170),
+        (7, 2, 173), (8, 2, 173), (9, 2, 173), (10, 2, 173),
+        (11, 2, 173), (12, 2, 173), (13, 2, 173), (14, 6, 173),
+    ],
+    /* 148 */ [
+        (7, 2, 178), (8, 2, 178), (9, 2, 178), (10, 2, 178),
+        (11, 2, 178), (12, 2, 178), (13, 2, 178), (14, 6, 178),
+        (7, 2, 181), (8, 2, 181), (9, 2, 181), (10, 2, 181),
+        (11, 2, 181), (12, 2, 181), (13, 2, 181), (14, 6, 181),
+    ],
+    /* 149 */ [
+        (7, 2, 185), (8, 2, 185), (9, 2, 185), (10, 2, 185),
+        (11, 2, 185), (12, 2, 185), (13, 2, 185), (14, 6, 185),
+        (7, 2, 186), (8, 2, 186), (9, 2, 186), (10, 2, 186),
+        (11, 2, 186), (12, 2, 186), (13, 2, 186), (14, 6, 186),
+    ],
+    /* 150 */ [
+        (7, 2, 187), (8, 2, 187), (9, 2, 187), (10, 2, 187),
+        (11, 2, 187), (12, 2, 187), (13, 2, 187), (14, 6, 187),
+        (7, 2, 189), (8, 2, 189), (9, 2, 189), (10, 2, 189),
+        (11, 2, 189), (12, 2, 189), (13, 2, 189), (14, 6, 189),
+    ],
+    /* 151 */ [
+        (7, 2, 190), (8, 2, 190), (9, 2, 190), (10, 2, 190),
+        (11, 2, 190), (12, 2, 190), (13, 2, 190), (14, 6, 190),
+        (7, 2, 196), (8, 2, 196), (9, 2, 196), (10, 2, 196),
+        (11, 2, 196), (12, 2, 196), (13, 2, 196), (14, 6, 196),
+    ],
+    /* 152 */ [
+        (7, 2, 198), (8, 2, 198), (9, 2, 198), (10, 2, 198),
+        (11, 2, 198), (12, 2, 198), (13, 2, 198), (14, 6, 198),
+        (7, 2, 228), (8, 2, 228), (9, 2, 228), (10, 2, 228),
+        (11, 2, 228), (12, 2, 228), (13, 2, 228), (14, 6, 228),
+    ],
+    /* 153 */ [
+        (7, 2, 232), (8, 2, 232), (9, 2, 232), (10, 2, 232),
+        (11, 2, 232), (12, 2, 232), (13, 2, 232), (14, 6, 232),
+        (7, 2, 233), (8, 2, 233), (9, 2, 233), (10, 2, 233),
+        (11, 2, 233), (12, 2, 233), (13, 2, 233), (14, 6, 233),
+    ],
+    /* 154 */ [
+        (3, 2, 1), (4, 2, 1), (5, 2, 1), (6, 6, 1),
+        (3, 2, 135), (4, 2, 135), (5, 2, 135), (6, 6, 135),
+        (3, 2, 137), (4, 2, 137), (5, 2, 137), (6, 6, 137),
+        (3, 2, 138), (4, 2, 138), (5, 2, 138), (6, 6, 138),
+    ],
+    /* 155 */ [
+        (3, 2, 139), (4, 2, 139), (5, 2, 139), (6, 6, 139),
+        (3, 2, 140), (4, 2, 140), (5, 2, 140), (6, 6, 140),
+        (3, 2, 141), (4, 2, 141), (5, 2, 141), (6, 6, 141),
+        (3, 2, 143), (4, 2, 143), (5, 2, 143), (6, 6, 143),
+    ],
+    /* 156 */ [
+        (3, 2, 147), (4, 2, 147), (5, 2, 147), (6, 6, 147),
+        (3, 2, 149), (4, 2, 149), (5, 2, 149), (6, 6, 149),
+        (3, 2, 150), (4, 2, 150), (5, 2, 150), (6, 6, 150),
+        (3, 2, 151), (4, 2, 151), (5, 2, 151), (6, 6, 151),
+    ],
+    /* 157 */ [
+        (3, 2, 152), (4, 2, 152), (5, 2, 152), (6, 6, 152),
+        (3, 2, 155), (4, 2, 155), (5, 2, 155), (6, 6, 155),
+        (3, 2, 157), (4, 2, 157), (5, 2, 157), (6, 6, 157),
+        (3, 2, 158), (4, 2, 158), (5, 2, 158), (6, 6, 158),
+    ],
+    /* 158 */ [
+        (3, 2, 165), (4, 2, 165), (5, 2, 165), (6, 6, 165),
+        (3, 2, 166), (4, 2, 166), (5, 2, 166), (6, 6, 166),
+        (3, 2, 168), (4, 2, 168), (5, 2, 168), (6, 6, 168),
+        (3, 2, 174), (4, 2, 174), (5, 2, 174), (6, 6, 174),
+    ],
+    /* 159 */ [
+        (3, 2, 175), (4, 2, 175), (5, 2, 175), (6, 6, 175),
+        (3, 2, 180), (4, 2, 180), (5, 2, 180), (6, 6, 180),
+        (3, 2, 182), (4, 2, 182), (5, 2, 182), (6, 6, 182),
+        (3, 2, 183), (4, 2, 183), (5, 2, 183), (6, 6, 183),
+    ],
+    /* 160 */ [
+        (3, 2, 188), (4, 2, 188), (5, 2, 188), (6, 6, 188),
+        (3, 2, 191), (4, 2, 191), (5, 2, 191), (6, 6, 191),
+        (3, 2, 197), (4, 2, 197), (5, 2, 197), (6, 6, 197),
+        (3, 2, 231), (4, 2, 231), (5, 2, 231), (6, 6, 231),
+    ],
+    /* 161 */ [
+        (3, 2, 239), (4, 2, 239), (5, 2, 239), (6, 6, 239),
+        (1, 2, 9), (2, 6, 9), (1, 2, 142), (2, 6, 142),
+        (1, 2, 144), (2, 6, 144), (1, 2, 145), (2, 6, 145),
+        (1, 2, 148), (2, 6, 148), (1, 2, 159), (2, 6, 159),
+    ],
+    /* 162 */ [
+        (1, 2, 171), (2, 6, 171), (1, 2, 206), (2, 6, 206),
+        (1, 2, 215), (2, 6, 215), (1, 2, 225), (2, 6, 225),
+        (1, 2, 236), (2, 6, 236), (1, 2, 237), (2, 6, 237),
+        (0, 6, 199), (0, 6, 207), (0, 6, 234), (0, 6, 235),
+    ],
+    /* 163 */ [
+        (205, 0, 0), (206, 0, 0), (207, 0, 0), (208, 0, 0),
+        (209, 0, 0), (210, 0, 0), (211, 0, 0), (212, 0, 0),
+        (213, 0, 0), (214, 0, 0), (215, 0, 0), (216, 0, 0),
+        (217, 0, 0), (218, 0, 0), (219, 0, 0), (220, 0, 0),
+    ],
+    /* 164 */ [
+        (7, 2, 1), (8, 2, 1), (9, 2, 1), (10, 2, 1),
+        (11, 2, 1), (12, 2, 1), (13, 2, 1), (14, 6, 1),
+        (7, 2, 135), (8, 2, 135), (9, 2, 135), (10, 2, 135),
+        (11, 2, 135), (12, 2, 135), (13, 2, 135), (14, 6, 135),
+    ],
+    /* 165 */ [
+        (7, 2, 137), (8, 2, 137), (9, 2, 137), (10, 2, 137),
+        (11, 2, 137), (12, 2, 137), (13, 2, 137), (14, 6, 137),
+        (7, 2, 138), (8, 2, 138), (9, 2, 138), (10, 2, 138),
+        (11, 2, 138), (12, 2, 138), (13, 2, 138), (14, 6, 138),
+    ],
+    /* 166 */ [
+        (7, 2, 139), (8, 2, 139), (9, 2, 139), (10, 2, 139),
+        (11, 2, 139), (12, 2, 139), (13, 2, 139), (14, 6, 139),
+        (7, 2, 140), (8, 2, 140), (9, 2, 140), (10, 2, 140),
+        (11, 2, 140), (12, 2, 140), (13, 2, 140), (14, 6, 140),
+    ],
+    /* 167 */ [
+        (7, 2, 141), (8, 2, 141), (9, 2, 141), (10, 2, 141),
+        (11, 2, 141), (12, 2, 141), (13, 2, 141), (14, 6, 141),
+        (7, 2, 143), (8, 2, 143), (9, 2, 143), (10, 2, 143),
+        (11, 2, 143), (12, 2, 143), (13, 2, 143), (14, 6, 143),
+    ],
+    /* 168 */ [
+        (7, 2, 147), (8, 2, 147), (9, 2, 147), (10, 2, 147),
+        (11, 2, 147), (12, 2, 147), (13, 2, 147), (14, 6, 147),
+        (7, 2, 149), (8, 2, 149), (9, 2, 149), (10, 2, 149),
+        (11, 2, 149), (12, 2, 149), (13, 2, 149), (14, 6, 149),
+    ],
+    /* 169 */ [
+        (7, 2, 150), (8, 2, 150), (9, 2, 150), (10, 2, 150),
+        (11, 2, 150), (12, 2, 150), (13, 2, 150), (14, 6, 150),
+        (7, 2, 151), (8, 2, 151), (9, 2, 151), (10, 2, 151),
+        (11, 2, 151), (12, 2, 151), (13, 2, 151), (14, 6, 151),
+    ],
+    /* 170 */ [
+        (7, 2, 152), (8, 2, 152), (9, 2, 152), (10, 2, 152),
+        (11, 2, 152), (12, 2, 152), (13, 2, 152), (14, 6, 152),
+        (7, 2, 155), (8, 2, 155), (9, 2, 155), (10, 2, 155),
+        (11, 2, 155), (12, 2, 155), (13, 2, 155), (14, 6, 155),
+    ],
+    /* 171 */ [
+        (7, 2, 157), (8, 2, 157), (9, 2, 157), (10, 2, 157),
+        (11, 2, 157), (12, 2, 157), (13, 2, 157), (14, 6, 157),
+        (7, 2, 158), (8, 2, 158), (9, 2, 158), (10, 2, 158),
+        (11, 2, 158), (12, 2, 158), (13, 2, 158), (14, 6, 158),
+    ],
+    /* 172 */ [
+        (7, 2, 165), (8, 2, 165), (9, 2, 165), (10, 2, 165),
+        (11, 2, 165), (12, 2, 165), (13, 2, 165), (14, 6, 165),
+        (7, 2, 166), (8, 2, 166), (9, 2, 166), (10, 2, 166),
+        (11, 2, 166), (12, 2, 166), (13, 2, 166), (14, 6, 166),
+    ],
+    /* 173 */ [
+        (7, 2, 168), (8, 2, 168), (9, 2, 168), (10, 2, 168),
+        (11, 2, 168), (12, 2, 168), (13, 2, 168), (14, 6, 168),
+        (7, 2, 174), (8, 2, 174), (9, 2, 174), (10, 2, 174),
+        (11, 2, 174), (12, 2, 174), (13, 2, 174), (14, 6, 174),
+    ],
+    /* 174 */ [
+        (7, 2, 175), (8, 2, 175), (9, 2, 175), (10, 2, 175),
+        (11, 2, 175), (12, 2, 175), (13, 2, 175), (14, 6, 175),
+        (7, 2, 180), (8, 2, 180), (9, 2, 180), (10, 2, 180),
+        (11, 2, 180), (12, 2, 180), (13, 2, 180), (14, 6, 180),
+    ],
+    /* 175 */ [
+        (7, 2, 182), (8, 2, 182), (9, 2, 182), (10, 2, 182),
+        (11, 2, 182), (12, 2, 182), (13, 2, 182), (14, 6, 182),
+        (7, 2, 183), (8, 2, 183), (9, 2, 183), (10, 2, 183),
+        (11, 2, 183), (12, 2, 183), (13, 2, 183), (14, 6, 183),
+    ],
+    /* 176 */ [
+        (7, 2, 188), (8, 2, 188), (9, 2, 188), (10, 2, 188),
+        (11, 2, 188), (12, 2, 188), (13, 2, 188), (14, 6, 188),
+        (7, 2, 191), (8, 2, 191), (9, 2, 191), (10, 2, 191),
+        (11, 2, 191), (12, 2, 191), (13, 2, 191), (14, 6, 191),
+    ],
+    /* 177 */ [
+        (7, 2, 197), (8, 2, 197), (9, 2, 197), (10, 2, 197),
+        (11, 2, 197), (12, 2, 197), (13, 2, 197), (14, 6, 197),
+        (7, 2, 231), (8, 2, 231), (9, 2, 231), (10, 2, 231),
+        (11, 2, 231), (12, 2, 231), (13, 2, 231), (14, 6, 231),
+    ],
+    /* 178 */ [
+        (7, 2, 239), (8, 2, 239), (9, 2, 239), (10, 2, 239),
+        (11, 2, 239), (12, 2, 239), (13, 2, 239), (14, 6, 239),
+        (3, 2, 9), (4, 2, 9), (5, 2, 9), (6, 6, 9),
+        (3, 2, 142), (4, 2, 142), (5, 2, 142), (6, 6, 142),
+    ],
+    /* 179 */ [
+        (3, 2, 144), (4, 2, 144), (5, 2, 144), (6, 6, 144),
+        (3, 2, 145), (4, 2, 145), (5, 2, 145), (6, 6, 145),
+        (3, 2, 148), (4, 2, 148), (5, 2, 148), (6, 6, 148),
+        (3, 2, 159), (4, 2, 159), (5, 2, 159), (6, 6, 159),
+    ],
+    /* 180 */ [
+        (3, 2, 171), (4, 2, 171), (5, 2, 171), (6, 6, 171),
+        (3, 2, 206), (4, 2, 206), (5, 2, 206), (6, 6, 206),
+        (3, 2, 215), (4, 2, 215), (5, 2, 215), (6, 6, 215),
+        (3, 2, 225), (4, 2, 225), (5, 2, 225), (6, 6, 225),
+    ],
+    /* 181 */ [
+        (3, 2, 236), (4, 2, 236), (5, 2, 236), (6, 6, 236),
+        (3, 2, 237), (4, 2, 237), (5, 2, 237), (6, 6, 237),
+        (1, 2, 199), (2, 6, 199), (1, 2, 207), (2, 6, 207),
+        (1, 2, 234), (2, 6, 234), (1, 2, 235), (2, 6, 235),
+    ],
+    /* 182 */ [
+        (0, 6, 192), (0, 6, 193), (0, 6, 200), (0, 6, 201),
+        (0, 6, 202), (0, 6, 205), (0, 6, 210), (0, 6, 213),
+        (0, 6, 218), (0, 6, 219), (0, 6, 238), (0, 6, 240),
+        (0, 6, 242), (0, 6, 243), (0, 6, 255), (221, 0, 0),
+    ],
+    /* 183 */ [
+        (222, 0, 0), (223, 0, 0), (224, 0, 0), (225, 0, 0),
+        (226, 0, 0), (227, 0, 0), (228, 0, 0), (229, 0, 0),
+        (230, 0, 0), (231, 0, 0), (232, 0, 0), (233, 0, 0),
+        (234, 0, 0), (235, 0, 0), (236, 0, 0), (237, 0, 0),
+    ],
+    /* 184 */ [
+        (7, 2, 9), (8, 2, 9), (9, 2, 9), (10, 2, 9),
+        (11, 2, 9), (12, 2, 9), (13, 2, 9), (14, 6, 9),
+        (7, 2, 142), (8, 2, 142), (9, 2, 142), (10, 2, 142),
+        (11, 2, 142), (12, 2, 142), (13, 2, 142), (14, 6, 142),
+    ],
+    /* 185 */ [
+        (7, 2, 144), (8, 2, 144), (9, 2, 144), (10, 2, 144),
+        (11, 2, 144), (12, 2, 144), (13, 2, 144), (14, 6, 144),
+        (7, 2, 145), (8, 2, 145), (9, 2, 145), (10, 2, 145),
+        (11, 2, 145), (12, 2, 145), (13, 2, 145), (14, 6, 145),
+    ],
+    /* 186 */ [
+        (7, 2, 148), (8, 2, 148), (9, 2, 148), (10, 2, 148),
+        (11, 2, 148), (12, 2, 148), (13, 2, 148), (14, 6, 148),
+        (7, 2, 159), (8, 2, 159), (9, 2, 159), (10, 2, 159),
+        (11, 2, 159), (12, 2, 159), (13, 2, 159), (14, 6, 159),
+    ],
+    /* 187 */ [
+        (7, 2, 171), (8, 2, 171), (9, 2, 171), (10, 2, 171),
+        (11, 2, 171), (12, 2, 171), (13, 2, 171), (14, 6, 171),
+        (7, 2, 206), (8, 2, 206), (9, 2, 206), (10, 2, 206),
+        (11, 2, 206), (12, 2, 206), (13, 2, 206), (14, 6, 206),
+    ],
+    /* 188 */ [
+        (7, 2, 215), (8, 2, 215), (9, 2, 215), (10, 2, 215),
+        (11, 2, 215), (12, 2, 215), (13, 2, 215), (14, 6, 215),
+        (7, 2, 225), (8, 2, 225), (9, 2, 225), (10, 2, 225),
+        (11, 2, 225), (12, 2, 225), (13, 2, 225), (14, 6, 225),
+    ],
+    /* 189 */ [
+        (7, 2, 236), (8, 2, 236), (9, 2, 236), (10, 2, 236),
+        (11, 2, 236), (12, 2, 236), (13, 2, 236), (14, 6, 236),
+        (7, 2, 237), (8, 2, 237), (9, 2, 237), (10, 2, 237),
+        (11, 2, 237), (12, 2, 237), (13, 2, 237), (14, 6, 237),
+    ],
+    /* 190 */ [
+        (3, 2, 199), (4, 2, 199), (5, 2, 199), (6, 6, 199),
+        (3, 2, 207), (4, 2, 207), (5, 2, 207), (6, 6, 207),
+        (3, 2, 234), (4, 2, 234), (5, 2, 234), (6, 6, 234),
+        (3, 2, 235), (4, 2, 235), (5, 2, 235), (6, 6, 235),
+    ],
+    /* 191 */ [
+        (1, 2, 192), (2, 6, 192), (1, 2, 193), (2, 6, 193),
+        (1, 2, 200), (2, 6, 200), (1, 2, 201), (2, 6, 201),
+        (1, 2, 202), (2, 6, 202), (1, 2, 205), (2, 6, 205),
+        (1, 2, 210), (2, 6, 210), (1, 2, 213), (2, 6, 213),
+    ],
+    /* 192 */ [
+        (1, 2, 218), (2, 6, 218), (1, 2, 219), (2, 6, 219),
+        (1, 2, 238), (2, 6, 238), (1, 2, 240), (2, 6, 240),
+        (1, 2, 242), (2, 6, 242), (1, 2, 243), (2, 6, 243),
+        (1, 2, 255), (2, 6, 255), (0, 6, 203), (0, 6, 204),
+    ],
+    /* 193 */ [
+        (0, 6, 211), (0, 6, 212), (0, 6, 214), (0, 6, 221),
+        (0, 6, 222), (0, 6, 223), (0, 6, 241), (0, 6, 244),
+        (0, 6, 245), (0, 6, 246), (0, 6, 247), (0, 6, 248),
+        (0, 6, 250), (0, 6, 251), (0, 6, 252), (0, 6, 253),
+    ],
+    /* 194 */ [
+        (0, 6, 254), (238, 0, 0), (239, 0, 0), (240, 0, 0),
+        (241, 0, 0), (242, 0, 0), (243, 0, 0), (244, 0, 0),
+        (245, 0, 0), (246, 0, 0), (247, 0, 0), (248, 0, 0),
+        (249, 0, 0), (250, 0, 0), (251, 0, 0), (252, 0, 0),
+    ],
+    /* 195 */ [
+        (7, 2, 199), (8, 2, 199), (9, 2, 199), (10, 2, 199),
+        (11, 2, 199), (12, 2, 199), (13, 2, 199), (14, 6, 199),
+        (7, 2, 207), (8, 2, 207), (9, 2, 207), (10, 2, 207),
+        (11, 2, 207), (12, 2, 207), (13, 2, 207), (14, 6, 207),
+    ],
+    /* 196 */ [
+        (7, 2, 234), (8, 2, 234), (9, 2, 234), (10, 2, 234),
+        (11, 2, 234), (12, 2, 234), (13, 2, 234), (14, 6, 234),
+        (7, 2, 235), (8, 2, 235), (9, 2, 235), (10, 2, 235),
+        (11, 2, 235), (12, 2, 235), (13, 2, 235), (14, 6, 235),
+    ],
+    /* 197 */ [
+        (3, 2, 192), (4, 2, 192), (5, 2, 192), (6, 6, 192),
+        (3, 2, 193), (4, 2, 193), (5, 2, 193), (6, 6, 193),
+        (3, 2, 200), (4, 2, 200), (5, 2, 200), (6, 6, 200),
+        (3, 2, 201), (4, 2, 201), (5, 2, 201), (6, 6, 201),
+    ],
+    /* 198 */ [
+        (3, 2, 202), (4, 2, 202), (5, 2, 202), (6, 6, 202),
+        (3, 2, 205), (4, 2, 205), (5, 2, 205), (6, 6, 205),
+        (3, 2, 210), (4, 2, 210), (5, 2, 210), (6, 6, 210),
+        (3, 2, 213), (4, 2, 213), (5, 2, 213), (6, 6, 213),
+    ],
+    /* 199 */ [
+        (3, 2, 218), (4, 2, 218), (5, 2, 218), (6, 6, 218),
+        (3, 2, 219), (4, 2, 219), (5, 2, 219), (6, 6, 219),
+        (3, 2, 238), (4, 2, 238), (5, 2, 238), (6, 6, 238),
+        (3, 2, 240), (4, 2, 240), (5, 2, 240), (6, 6, 240),
+    ],
+    /* 200 */ [
+        (3, 2, 242), (4, 2, 242), (5, 2, 242), (6, 6, 242),
+        (3, 2, 243), (4, 2, 243), (5, 2, 243), (6, 6, 243),
+        (3, 2, 255), (4, 2, 255), (5, 2, 255), (6, 6, 255),
+        (1, 2, 203), (2, 6, 203), (1, 2, 204), (2, 6, 204),
+    ],
+    /* 201 */ [
+        (1, 2, 211), (2, 6, 211), (1, 2, 212), (2, 6, 212),
+        (1, 2, 214), (2, 6, 214), (1, 2, 221), (2, 6, 221),
+        (1, 2, 222), (2, 6, 222), (1, 2, 223), (2, 6, 223),
+        (1, 2, 241), (2, 6, 241), (1, 2, 244), (2, 6, 244),
+    ],
+    /* 202 */ [
+        (1, 2, 245), (2, 6, 245), (1, 2, 246), (2, 6, 246),
+        (1, 2, 247), (2, 6, 247), (1, 2, 248), (2, 6, 248),
+        (1, 2, 250), (2, 6, 250), (1, 2, 251), (2, 6, 251),
+        (1, 2, 252), (2, 6, 252), (1, 2, 253), (2, 6, 253),
+    ],
+    /* 203 */ [
+        (1, 2, 254), (2, 6, 254), (0, 6, 2), (0, 6, 3),
+        (0, 6, 4), (0, 6, 5), (0, 6, 6), (0, 6, 7),
+        (0, 6, 8), (0, 6, 11), (0, 6, 12), (0, 6, 14),
+        (0, 6, 15), (0, 6, 16), (0, 6, 17), (0, 6, 18),
+    ],
+    /* 204 */ [
+        (0, 6, 19), (0, 6, 20), (0, 6, 21), (0, 6, 23),
+        (0, 6, 24), (0, 6, 25), (0, 6, 26), (0, 6, 27),
+        (0, 6, 28), (0, 6, 29), (0, 6, 30), (0, 6, 31),
+        (0, 6, 127), (0, 6, 220), (0, 6, 249), (253, 0, 0),
+    ],
+    /* 205 */ [
+        (7, 2, 192), (8, 2, 192), (9, 2, 192), (10, 2, 192),
+        (11, 2, 192), (12, 2, 192), (13, 2, 192), (14, 6, 192),
+        (7, 2, 193), (8, 2, 193), (9, 2, 193), (10, 2, 193),
+        (11, 2, 193), (12, 2, 193), (13, 2, 193), (14, 6, 193),
+    ],
+    /* 206 */ [
+        (7, 2, 200), (8, 2, 200), (9, 2, 200), (10, 2, 200),
+        (11, 2, 200), (12, 2, 200), (13, 2, 200), (14, 6, 200),
+        (7, 2, 201), (8, 2, 201), (9, 2, 201), (10, 2, 201),
+        (11, 2, 201), (12, 2, 201), (13, 2, 201), (14, 6, 201),
+    ],
+    /* 207 */ [
+        (7, 2, 202), (8, 2, 202), (9, 2, 202), (10, 2, 202),
+        (11, 2, 202), (12, 2, 202), (13, 2, 202), (14, 6, 202),
+        (7, 2, 205), (8, 2, 205), (9, 2, 205), (10, 2, 205),
+        (11, 2, 205), (12, 2, 205), (13, 2, 205), (14, 6, 205),
+    ],
+    /* 208 */ [
+        (7, 2, 210), (8, 2, 210), (9, 2, 210), (10, 2, 210),
+        (11, 2, 210), (12, 2, 210), (13, 2, 210), (14, 6, 210),
+        (7, 2, 213), (8, 2, 213), (9, 2, 213), (10, 2, 213),
+        (11, 2, 213), (12, 2, 213), (13, 2, 213), (14, 6, 213),
+    ],
+    /* 209 */ [
+        (7, 2, 218), (8, 2, 218), (9, 2, 218), (10, 2, 218),
+        (11, 2, 218), (12, 2, 218), (13, 2, 218), (14, 6, 218),
+        (7, 2, 219), (8, 2, 219), (9, 2, 219), (10, 2, 219),
+        (11, 2, 219), (12, 2, 219), (13, 2, 219), (14, 6, 219),
+    ],
+    /* 210 */ [
+        (7, 2, 238), (8, 2, 238), (9, 2, 238), (10, 2, 238),
+        (11, 2, 238), (12, 2, 238), (13, 2, 238), (14, 6, 238),
+        (7, 2, 240), (8, 2, 240), (9, 2, 240), (10, 2, 240),
+        (11, 2, 240), (12, 2, 240), (13, 2, 240), (14, 6, 240),
+    ],
+    /* 211 */ [
+        (7, 2, 242), (8, 2, 242), (9, 2, 242), (10, 2, 242),
+        (11, 2, 242), (12, 2, 242), (13, 2, 242), (14, 6, 242),
+        (7, 2, 243), (8, 2, 243), (9, 2, 243), (10, 2, 243),
+        (11, 2, 243), (12, 2, 243), (13, 2, 243), (14, 6, 243),
+    ],
+    /* 212 */ [
+        (7, 2, 255), (8, 2, 255), (9, 2, 255), (10, 2, 255),
+        (11, 2, 255), (12, 2, 255), (13, 2, 255), (14, 6, 255),
+        (3, 2, 203), (4, 2, 203), (5, 2, 203), (6, 6, 203),
+        (3, 2, 204), (4, 2, 204), (5, 2, 204), (6, 6, 204),
+    ],
+    /* 213 */ [
+        (3, 2, 211), (4, 2, 211), (5, 2, 211), (6, 6, 211),
+        (3, 2, 212), (4, 2, 212), (5, 2, 212), (6, 6, 212),
+        (3, 2, 214), (4, 2, 214), (5, 2, 214), (6, 6, 214),
+        (3, 2, 221), (4, 2, 221), (5, 2, 221), (6, 6, 221),
+    ],
+    /* 214 */ [
+        (3, 2, 222), (4, 2, 222), (5, 2, 222), (6, 6, 222),
+        (3, 2, 223), (4, 2, 223), (5, 2, 223), (6, 6, 223),
+        (3, 2, 241), (4, 2, 241), (5, 2, 241), (6, 6, 241),
+        (3, 2, 244), (4, 2, 244), (5, 2, 244), (6, 6, 244),
+    ],
+    /* 215 */ [
+        (3, 2, 245), (4, 2, 245), (5, 2, 245), (6, 6, 245),
+        (3, 2, 246), (4, 2, 246), (5, 2, 246), (6, 6, 246),
+        (3, 2, 247), (4, 2, 247), (5, 2, 247), (6, 6, 247),
+        (3, 2, 248), (4, 2, 248), (5, 2, 248), (6, 6, 248),
+    ],
+    /* 216 */ [
+        (3, 2, 250), (4, 2, 250), (5, 2, 250), (6, 6, 250),
+        (3, 2, 251), (4, 2, 251), (5, 2, 251), (6, 6, 251),
+        (3, 2, 252), (4, 2, 252), (5, 2, 252), (6, 6, 252),
+        (3, 2, 253), (4, 2, 253), (5, 2, 253), (6, 6, 253),
+    ],
+    /* 217 */ [
+        (3, 2, 254), (4, 2, 254), (5, 2, 254), (6, 6, 254),
+        (1, 2, 2), (2, 6, 2), (1, 2, 3), (2, 6, 3),
+        (1, 2, 4), (2, 6, 4), (1, 2, 5), (2, 6, 5),
+        (1, 2, 6), (2, 6, 6), (1, 2, 7), (2, 6, 7),
+    ],
+    /* 218 */ [
+        (1, 2, 8), (2, 6, 8), (1, 2, 11), (2, 6, 11),
+        (1, 2, 12), (2, 6, 12), (1, 2, 14), (2, 6, 14),
+        (1, 2, 15), (2, 6, 15), (1, 2, 16), (2, 6, 16),
+        (1, 2, 17), (2, 6, 17), (1, 2, 18), (2, 6, 18),
+    ],
+    /* 219 */ [
+        (1, 2, 19), (2, 6, 19), (1, 2, 20), (2, 6, 20),
+        (1, 2, 21), (2, 6, 21), (1, 2, 23), (2, 6, 23),
+        (1, 2, 24), (2, 6, 24), (1, 2, 25), (2, 6, 25),
+        (1, 2, 26), (2, 6, 26), (1, 2, 27), (2, 6, 27),
+    ],
+    /* 220 */ [
+        (1, 2, 28), (2, 6, 28), (1, 2, 29), (2, 6, 29),
+        (1, 2, 30), (2, 6, 30), (1, 2, 31), (2, 6, 31),
+        (1, 2, 127), (2, 6, 127), (1, 2, 220), (2, 6, 220),
+        (1, 2, 249), (2, 6, 249), (254, 0, 0), (255, 0, 0),
+    ],
+    /* 221 */ [
+        (7, 2, 203), (8, 2, 203), (9, 2, 203), (10, 2, 203),
+        (11, 2, 203), (12, 2, 203), (13, 2, 203), (14, 6, 203),
+        (7, 2, 204), (8, 2, 204), (9, 2, 204), (10, 2, 204),
+        (11, 2, 204), (12, 2, 204), (13, 2, 204), (14, 6, 204),
+    ],
+    /* 222 */ [
+        (7, 2, 211), (8, 2, 211), (9, 2, 211), (10, 2, 211),
+        (11, 2, 211), (12, 2, 211), (13, 2, 211), (14, 6, 211),
+        (7, 2, 212), (8, 2, 212), (9, 2, 212), (10, 2, 212),
+        (11, 2, 212), (12, 2, 212), (13, 2, 212), (14, 6, 212),
+    ],
+    /* 223 */ [
+        (7, 2, 214), (8, 2, 214), (9, 2, 214), (10, 2, 214),
+        (11, 2, 214), (12, 2, 214), (13, 2, 214), (14, 6, 214),
+        (7, 2, 221), (8, 2, 221), (9, 2, 221), (10, 2, 221),
+        (11, 2, 221), (12, 2, 221), (13, 2, 221), (14, 6, 221),
+    ],
+    /* 224 */ [
+        (7, 2, 222), (8, 2, 222), (9, 2, 222), (10, 2, 222),
+        (11, 2, 222), (12, 2, 222), (13, 2, 222), (14, 6, 222),
+        (7, 2, 223), (8, 2, 223), (9, 2, 223), (10, 2, 223),
+        (11, 2, 223), (12, 2, 223), (13, 2, 223), (14, 6, 223),
+    ],
+    /* 225 */ [
+        (7, 2, 241), (8, 2, 241), (9, 2, 241), (10, 2, 241),
+        (11, 2, 241), (12, 2, 241), (13, 2, 241), (14, 6, 241),
+        (7, 2, 244), (8, 2, 244), (9, 2, 244), (10, 2, 244),
+        (11, 2, 244), (12, 2, 244), (13, 2, 244), (14, 6, 244),
+    ],
+    /* 226 */ [
+        (7, 2, 245), (8, 2, 245), (9, 2, 245), (10, 2, 245),
+        (11, 2, 245), (12, 2, 245), (13, 2, 245), (14, 6, 245),
+        (7, 2, 246), (8, 2, 246), (9, 2, 246), (10, 2, 246),
+        (11, 2, 246), (12, 2, 246), (13, 2, 246), (14, 6, 246),
+    ],
+    /* 227 */ [
+        (7, 2, 247), (8, 2, 247), (9, 2, 247), (10, 2, 247),
+        (11, 2, 247), (12, 2, 247), (13, 2, 247), (14, 6, 247),
+        (7, 2, 248), (8, 2, 248), (9, 2, 248), (10, 2, 248),
+        (11, 2, 248), (12, 2, 248), (13, 2, 248), (14, 6, 248),
+    ],
+    /* 228 */ [
+        (7, 2, 250), (8, 2, 250), (9, 2, 250), (10, 2, 250),
+        (11, 2, 250), (12, 2, 250), (13, 2, 250), (14, 6, 250),
+        (7, 2, 251), (8, 2, 251), (9, 2, 251), (10, 2, 251),
+        (11, 2, 251), (12, 2, 251), (13, 2, 251), (14, 6, 251),
+    ],
+    /* 229 */ [
+        (7, 2, 252), (8, 2, 252), (9, 2, 252), (10, 2, 252),
+        (11, 2, 252), (12, 2, 252), (13, 2, 252), (14, 6, 252),
+        (7, 2, 253), (8, 2, 253), (9, 2, 253), (10, 2, 253),
+        (11, 2, 253), (12, 2, 253), (13, 2, 253), (14, 6, 253),
+    ],
+    /* 230 */ [
+        (7, 2, 254), (8, 2, 254), (9, 2, 254), (10, 2, 254),
+        (11, 2, 254), (12, 2, 254), (13, 2, 254), (14, 6, 254),
+        (3, 2, 2), (4, 2, 2), (5, 2, 2), (6, 6, 2),
+        (3, 2, 3), (4, 2, 3), (5, 2, 3), (6, 6, 3),
+    ],
+    /* 231 */ [
+        (3, 2, 4), (4, 2, 4), (5, 2, 4), (6, 6, 4),
+        (3, 2, 5), (4, 2, 5), (5, 2, 5), (6, 6, 5),
+        (3, 2, 6), (4, 2, 6), (5, 2, 6), (6, 6, 6),
+        (3, 2, 7), (4, 2, 7), (5, 2, 7), (6, 6, 7),
+    ],
+    /* 232 */ [
+        (3, 2, 8), (4, 2, 8), (5, 2, 8), (6, 6, 8),
+        (3, 2, 11), (4, 2, 11), (5, 2, 11), (6, 6, 11),
+        (3, 2, 12), (4, 2, 12), (5, 2, 12), (6, 6, 12),
+        (3, 2, 14), (4, 2, 14), (5, 2, 14), (6, 6, 14),
+    ],
+    /* 233 */ [
+        (3, 2, 15), (4, 2, 15), (5, 2, 15), (6, 6, 15),
+        (3, 2, 16), (4, 2, 16), (5, 2, 16), (6, 6, 16),
+        (3, 2, 17), (4, 2, 17), (5, 2, 17), (6, 6, 17),
+        (3, 2, 18), (4, 2, 18), (5, 2, 18), (6, 6, 18),
+    ],
+    /* 234 */ [
+        (3, 2, 19), (4, 2, 19), (5, 2, 19), (6, 6, 19),
+        (3, 2, 20), (4, 2, 20), (5, 2, 20), (6, 6, 20),
+        (3, 2, 21), (4, 2, 21), (5, 2, 21), (6, 6, 21),
+        (3, 2, 23), (4, 2, 23), (5, 2, 23), (6, 6, 23),
+    ],
+    /* 235 */ [
+        (3, 2, 24), (4, 2, 24), (5, 2, 24), (6, 6, 24),
+        (3, 2, 25), (4, 2, 25), (5, 2, 25), (6, 6, 25),
+        (3, 2, 26), (4, 2, 26), (5, 2, 26), (6, 6, 26),
+        (3, 2, 27), (4, 2, 27), (5, 2, 27), (6, 6, 27),
+    ],
+    /* 236 */ [
+        (3, 2, 28), (4, 2, 28), (5, 2, 28), (6, 6, 28),
+        (3, 2, 29), (4, 2, 29), (5, 2, 29), (6, 6, 29),
+        (3, 2, 30), (4, 2, 30), (5, 2, 30), (6, 6, 30),
+        (3, 2, 31), (4, 2, 31), (5, 2, 31), (6, 6, 31),
+    ],
+    /* 237 */ [
+        (3, 2, 127), (4, 2, 127), (5, 2, 127), (6, 6, 127),
+        (3, 2, 220), (4, 2, 220), (5, 2, 220), (6, 6, 220),
+        (3, 2, 249), (4, 2, 249), (5, 2, 249), (6, 6, 249),
+        (0, 6, 10), (0, 6, 13), (0, 6, 22), (0, 1, 0),
+    ],
+    /* 238 */ [
+        (7, 2, 2), (8, 2, 2), (9, 2, 2), (10, 2, 2),
+        (11, 2, 2), (12, 2, 2), (13, 2, 2), (14, 6, 2),
+        (7, 2, 3), (8, 2, 3), (9, 2, 3), (10, 2, 3),
+        (11, 2, 3), (12, 2, 3), (13, 2, 3), (14, 6, 3),
+    ],
+    /* 239 */ [
+        (7, 2, 4), (8, 2, 4), (9, 2, 4), (10, 2, 4),
+        (11, 2, 4), (12, 2, 4), (13, 2, 4), (14, 6, 4),
+        (7, 2, 5), (8, 2, 5), (9, 2, 5), (10, 2, 5),
+        (11, 2, 5), (12, 2, 5), (13, 2, 5), (14, 6, 5),
+    ],
+    /* 240 */ [
+        (7, 2, 6), (8, 2, 6), (9, 2, 6), (10, 2, 6),
+        (11, 2, 6), (12, 2, 6), (13, 2, 6), (14, 6, 6),
+        (7, 2, 7), (8, 2, 7), (9, 2, 7), (10, 2, 7),
+        (11, 2, 7), (12, 2, 7), (13, 2, 7), (14, 6, 7),
+    ],
+    /* 241 */ [
+        (7, 2, 8), (8, 2, 8), (9, 2, 8), (10, 2, 8),
+        (11, 2, 8), (12, 2, 8), (13, 2, 8), (14, 6, 8),
+        (7, 2, 11), (8, 2, 11), (9, 2, 11), (10, 2, 11),
+        (11, 2, 11), (12, 2, 11), (13, 2, 11), (14, 6, 11),
+    ],
+    /* 242 */ [
+        (7, 2, 12), (8, 2, 12), (9, 2, 12), (10, 2, 12),
+        (11, 2, 12), (12, 2, 12), (13, 2, 12), (14, 6, 12),
+        (7, 2, 14), (8, 2, 14), (9, 2, 14), (10, 2, 14),
+        (11, 2, 14), (12, 2, 14), (13, 2, 14), (14, 6, 14),
+    ],
+    /* 243 */ [
+        (7, 2, 15), (8, 2, 15), (9, 2, 15), (10, 2, 15),
+        (11, 2, 15), (12, 2, 15), (13, 2, 15), (14, 6, 15),
+        (7, 2, 16), (8, 2, 16), (9, 2, 16), (10, 2, 16),
+        (11, 2, 16), (12, 2, 16), (13, 2, 16), (14, 6, 16),
+    ],
+    /* 244 */ [
+        (7, 2, 17), (8, 2, 17), (9, 2, 17), (10, 2, 17),
+        (11, 2, 17), (12, 2, 17), (13, 2, 17), (14, 6, 17),
+        (7, 2, 18), (8, 2, 18), (9, 2, 18), (10, 2, 18),
+        (11, 2, 18), (12, 2, 18), (13, 2, 18), (14, 6, 18),
+    ],
+    /* 245 */ [
+        (7, 2, 19), (8, 2, 19), (9, 2, 19), (10, 2, 19),
+        (11, 2, 19), (12, 2, 19), (13, 2, 19), (14, 6, 19),
+        (7, 2, 20), (8, 2, 20), (9, 2, 20), (10, 2, 20),
+        (11, 2, 20), (12, 2, 20), (13, 2, 20), (14, 6, 20),
+    ],
+    /* 246 */ [
+        (7, 2, 21), (8, 2, 21), (9, 2, 21), (10, 2, 21),
+        (11, 2, 21), (12, 2, 21), (13, 2, 21), (14, 6, 21),
+        (7, 2, 23), (8, 2, 23), (9, 2, 23), (10, 2, 23),
+        (11, 2, 23), (12, 2, 23), (13, 2, 23), (14, 6, 23),
+    ],
+    /* 247 */ [
+        (7, 2, 24), (8, 2, 24), (9, 2, 24), (10, 2, 24),
+        (11, 2, 24), (12, 2, 24), (13, 2, 24), (14, 6, 24),
+        (7, 2, 25), (8, 2, 25), (9, 2, 25), (10, 2, 25),
+        (11, 2, 25), (12, 2, 25), (13, 2, 25), (14, 6, 25),
+    ],
+    /* 248 */ [
+        (7, 2, 26), (8, 2, 26), (9, 2, 26), (10, 2, 26),
+        (11, 2, 26), (12, 2, 26), (13, 2, 26), (14, 6, 26),
+        (7, 2, 27), (8, 2, 27), (9, 2, 27), (10, 2, 27),
+        (11, 2, 27), (12, 2, 27), (13, 2, 27), (14, 6, 27),
+    ],
+    /* 249 */ [
+        (7, 2, 28), (8, 2, 28), (9, 2, 28), (10, 2, 28),
+        (11, 2, 28), (12, 2, 28), (13, 2, 28), (14, 6, 28),
+        (7, 2, 29), (8, 2, 29), (9, 2, 29), (10, 2, 29),
+        (11, 2, 29), (12, 2, 29), (13, 2, 29), (14, 6, 29),
+    ],
+    /* 250 */ [
+        (7, 2, 30), (8, 2, 30), (9, 2, 30), (10, 2, 30),
+        (11, 2, 30), (12, 2, 30), (13, 2, 30), (14, 6, 30),
+        (7, 2, 31), (8, 2, 31), (9, 2, 31), (10, 2, 31),
+        (11, 2, 31), (12, 2, 31), (13, 2, 31), (14, 6, 31),
+    ],
+    /* 251 */ [
+        (7, 2, 127), (8, 2, 127), (9, 2, 127), (10, 2, 127),
+        (11, 2, 127), (12, 2, 127), (13, 2, 127), (14, 6, 127),
+        (7, 2, 220), (8, 2, 220), (9, 2, 220), (10, 2, 220),
+        (11, 2, 220), (12, 2, 220), (13, 2, 220), (14, 6, 220),
+    ],
+    /* 252 */ [
+        (7, 2, 249), (8, 2, 249), (9, 2, 249), (10, 2, 249),
+        (11, 2, 249), (12, 2, 249), (13, 2, 249), (14, 6, 249),
+        (1, 2, 10), (2, 6, 10), (1, 2, 13), (2, 6, 13),
+        (1, 2, 22), (2, 6, 22), (0, 1, 0), (0, 1, 0),
+    ],
+    /* 253 */ [
+        (3, 2, 10), (4, 2, 10), (5, 2, 10), (6, 6, 10),
+        (3, 2, 13), (4, 2, 13), (5, 2, 13), (6, 6, 13),
+        (3, 2, 22), (4, 2, 22), (5, 2, 22), (6, 6, 22),
+        (0, 1, 0), (0, 1, 0), (0, 1, 0), (0, 1, 0),
+    ],
+    /* 254 */ [
+        (7, 2, 10), (8, 2, 10), (9, 2, 10), (10, 2, 10),
+        (11, 2, 10), (12, 2, 10), (13, 2, 10), (14, 6, 10),
+        (7, 2, 13), (8, 2, 13), (9, 2, 13), (10, 2, 13),
+        (11, 2, 13), (12, 2, 13), (13, 2, 13), (14, 6, 13),
+    ],
+    /* 255 */ [
+        (7, 2, 22), (8, 2, 22), (9, 2, 22), (10, 2, 22),
+        (11, 2, 22), (12, 2, 22), (13, 2, 22), (14, 6, 22),
+        (0, 1, 0), (0, 1, 0), (0, 1, 0), (0, 1, 0),
+        (0, 1, 0), (0, 1, 0), (0, 1, 0), (0, 1, 0),
+    ],
+];
+
+// A child of a node of the Huffman tree
+#[cfg(test)]
+enum Child {
+    Empty,
+    Node(uint),
+    Leaf(uint),
+}
+
+// A node of the Huffman tree
+#[cfg(test)]
+struct TreeNode {
+    children: [Child, ..2],
+    depth: uint,
+    ones: bool, // Whether the path from the root is all ones, i.e. a prefix of EOS
+}
+
+// Build the Huffman tree. Nodes are numbered in the order they are created.
+#[cfg(test)]
+fn build_tree() -> ~[TreeNode] {
+    let mut tree = ~[TreeNode { children: [Empty, Empty], depth: 0, ones: true }];
+
+    for (symbol, &(code, length)) in HUFFMAN_CODES.iter().enumerate() {
+        let length = length as uint;
+        let mut node = 0;
+
+        for i in range(0, length) {
+            let bit = ((code >> (length - 1 - i)) & 1) as uint;
+
+            if i == length - 1 {
+                tree[node].children[bit] = Leaf(symbol);
+                break;
+            }
+
+            let child = tree[node].children[bit];
+            node = match child {
+                Node(next) => next,
+                _ => {
+                    let ones = tree[node].ones && bit == 1;
+                    tree.push(TreeNode { children: [Empty, Empty], depth: i + 1, ones: ones });
+                    tree[node].children[bit] = Node(tree.len() - 1);
+                    tree.len() - 1
+                }
+            };
+        }
+    }
+
+    tree
+}
+
+// Build HUFFMAN_DECODE_TABLE from HUFFMAN_CODES
+#[cfg(test)]
+fn build_decode_table() -> ~[~[(u8, u8, u8)]] {
+    let tree = build_tree();
+
+    // The nodes in breadth-first order, and their state numbers
+    let mut order = ~[0u];
+    let mut i = 0;
+    while i < order.len() {
+        let node = order[i];
+        for &child in tree[node].children.iter() {
+            match child {
+                Node(next) => order.push(next),
+                _ => {}
+            }
+        }
+        i += 1;
+    }
+
+    let mut states = ~[];
+    states.grow(tree.len(), &0u);
+    for (state, &node) in order.iter().enumerate() {
+        states[node] = state;
+    }
+
+    order.iter().map(|&start| {
+        range(0u, 16).map(|nibble| {
+            let mut node = start;
+            let mut symbol = None;
+
+            for i in range(0u, 4) {
+                match tree[node].children[(nibble >> (3 - i)) & 1] {
+                    Node(next) => node = next,
+                    Leaf(256)  => return (0, HUFFMAN_FAIL, 0),
+                    Leaf(sym)  => {
+                        symbol = Some(sym);
+                        node = 0;
+                    },
+                    Empty      => fail!("The Huffman code is not complete"),
+                }
+            }
+
+            let mut flags = 0;
+            if symbol.is_some() {
+                flags |= HUFFMAN_SYMBOL;
+            }
+            // Padding is at most 7 bits of EOS
+            if tree[node].ones && tree[node].depth < 8 {
+                flags |= HUFFMAN_ACCEPTED;
+            }
+
+            (states[node] as u8, flags, symbol.unwrap_or(0) as u8)
+        }).collect()
+    }).collect()
+}
+
+// The table in the format of HUFFMAN_DECODE_TABLE above
+#[cfg(test)]
+fn format_decode_table(table: &[~[(u8, u8, u8)]]) -> ~str {
+    let mut text = ~"";
+
+    for (state, row) in table.iter().enumerate() {
+        text.push_str(format!("    /* {:3u} */ [\n", state));
+        for entries in row.chunks(4) {
+            let entries: ~[~str] = entries.iter().map(|&(next, flags, symbol)| format!("({}, {}, {})", next, flags, symbol)).collect();
+            text.push_str(format!("        {},\n", entries.connect(", ")));
+        }
+        text.push_str("    ],\n");
+    }
+
+    text
+}
+
+#[test]
+fn huffman_decode_table_test() {
+    let table = build_decode_table();
+    assert!(table.len() == 256);
+
+    let mut same = true;
+    for (state, row) in table.iter().enumerate() {
+        for (nibble, entry) in row.iter().enumerate() {
+            same = same && *entry == HUFFMAN_DECODE_TABLE[state][nibble];
+        }
+    }
+
+    if !same {
+        println!("{}", format_decode_table(table.as_slice()));
+        fail!("HUFFMAN_DECODE_TABLE does not match HUFFMAN_CODES, see the rebuilt table above");
+    }
+}
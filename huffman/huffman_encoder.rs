@@ -1,82 +1,95 @@
-use std::num;
+use std::slice;
 
 use huffman::huffman_codes::HUFFMAN_CODES;
 
-pub struct HuffmanEncoder {
-    result: ~[u8],
-}
+pub struct HuffmanEncoder;
 
 impl HuffmanEncoder {
     pub fn new() -> HuffmanEncoder {
-        HuffmanEncoder {
-            result: ~[],
-        }
+        HuffmanEncoder
     }
 
-    pub fn encode(&mut self, bytes: ~[u8]) -> ~[u8] {
-        self.result = ~[];
+    pub fn encode(&self, bytes: &[u8]) -> ~[u8] {
+        let mut result: ~[u8] = slice::with_capacity(encoded_len(bytes));
 
-        let mut space = 8;
-
-        if bytes.len() == 0 {
-            return bytes;
-        }
+        // Codes are appended to the least significant end of `bits`.
+        // `bit_count` is the number of bits not yet written to the result.
+        // It is below 32 before a code (at most 30 bits) is appended, so 64 bits always suffice.
+        let mut bits: u64 = 0;
+        let mut bit_count: uint = 0;
 
         for byte in bytes.iter() {
-            let mut code: uint = num::from_str_radix(HUFFMAN_CODES[*byte].clone(), 2).unwrap();
-            let mut length = HUFFMAN_CODES[*byte].len();
-
-            while length != 0 {
-                if space >= length {
-                    self.add_code(code << (space - length), space);
-                    code = 0;
-                    space -= length;
-                    length = 0;
-                } else {
-                    let shift = length - space;
-                    let msb = code >> shift;
-                    self.add_code(msb, space);
-                    code -= msb << shift;
-                    length -= space;
-                    space = 0;
-                }
-
-                if space == 0 {
-                    space = 8;
-                }
+            let (code, length) = HUFFMAN_CODES[*byte as uint];
+
+            bits = (bits << length) | code as u64;
+            bit_count += length as uint;
+
+            // Write 32 bits at a time
+            if bit_count >= 32 {
+                bit_count -= 32;
+                let word = (bits >> bit_count) as u32;
+
+                result.push((word >> 24) as u8);
+                result.push((word >> 16) as u8);
+                result.push((word >> 8) as u8);
+                result.push(word as u8);
             }
         }
 
-        if space != 8 {
-            let code: uint = num::from_str_radix(HUFFMAN_CODES[256].clone(), 2).unwrap();
-            self.add_code(code >> (HUFFMAN_CODES[256].len() - space), space);
+        while bit_count >= 8 {
+            bit_count -= 8;
+            result.push((bits >> bit_count) as u8);
+        }
+
+        // Pad the last octet with the most significant bits of EOS (all ones)
+        if bit_count > 0 {
+            result.push(((bits << (8 - bit_count)) as u8) | (0xFF >> bit_count));
         }
 
-        self.result.clone()
+        result
     }
+}
 
+// The length in octets of the Huffman encoding of the given bytes (including padding)
+pub fn encoded_len(bytes: &[u8]) -> uint {
+    let bit_count = bytes.iter().fold(0, |sum, byte| {
+        let (_, length) = HUFFMAN_CODES[*byte as uint];
+        sum + length as uint
+    });
 
-    fn add_code(&mut self, code: uint, space: uint) {
-        if space == 8 {
-            self.result.push(code.to_u8().unwrap());
-        } else {
-            self.result[self.result.len() - 1] |= code.to_u8().unwrap();
-        }
-    }    
+    (bit_count + 7) / 8
 }
 
-
 #[test]
 fn huffman_encoder_test() {
-    let mut encoder = HuffmanEncoder::new();
+    let encoder = HuffmanEncoder::new();
     let mut ascii_bytes: ~[u8] = ~[];
 
     for i in range(0, 256) {
         ascii_bytes.push(i.to_u8().unwrap());
     }
 
-    let huffman_codes = ~[255, 255, 238, 191, 255, 251, 191, 255, 254, 243, 255, 255, 189, 255, 255, 239, 191, 255, 251, 255, 255, 255, 3, 255, 255, 193, 255, 255, 240, 191, 255, 252, 63, 255, 255, 19, 255, 255, 197, 255, 255, 241, 191, 255, 252, 127, 255,255, 35, 255, 255, 201, 255, 255, 242, 191, 255, 252, 191, 255, 255, 51, 255, 255, 205, 255, 255, 243, 191, 255, 252, 255, 255, 255, 67, 255, 255, 209, 255, 255, 244, 191, 255, 253, 63, 255, 255, 83, 255, 255, 213, 255, 255, 245, 191, 255,253, 127, 255, 255, 99, 255, 255, 217, 55, 255, 62, 31, 254, 127, 252, 123, 39,255, 127, 175, 143, 247, 254, 101, 204, 249, 192, 73, 16, 67, 20, 114, 75, 55, 103, 255, 242, 127, 255, 191, 239, 255, 236, 253, 189, 218, 59, 244, 234, 249, 120, 124, 254, 159, 93, 123, 60, 124, 190, 223, 125, 180, 121, 254, 63, 62, 159, 175, 223, 252, 255, 255, 246, 191, 239, 255, 187, 191, 255, 228, 239, 85, 43, 225, 85, 108, 245, 246, 178, 219, 155, 127, 243, 12, 93, 199, 151, 62, 157, 125, 255, 255, 191, 249, 255, 254, 255, 223, 255, 255, 111, 255, 255, 220, 255, 255, 247, 127, 255, 253, 239, 255, 255, 127, 255, 255, 224, 255, 255, 248, 127, 255, 254, 47, 255, 255, 143, 255, 255, 228, 255, 255, 249, 127, 255, 254, 111, 255, 255, 159, 255, 255, 232, 255, 255, 250, 127, 255, 254, 175, 255, 255, 175, 255, 255, 236, 255, 255, 251, 127, 255, 254, 239, 255, 255, 191, 255, 255, 240, 255, 255, 252, 127, 255, 255, 47, 255, 255, 207, 255, 255, 244, 255, 255, 253, 127, 255, 255, 111, 255, 255, 223, 255, 255, 248, 255, 255, 254, 127, 255, 255, 175, 255, 255, 239, 255, 255, 252, 255, 255, 255, 127, 255, 255, 239, 255, 255, 255, 255, 255, 1, 255, 255, 129, 255, 255, 193, 127, 255, 224, 255, 255, 240, 159, 255, 248, 95, 255, 252, 55, 255, 254, 31, 255, 255, 17, 255, 255, 137, 255, 255, 197,127, 255, 226, 255, 255, 241, 159, 255, 248, 223, 255, 252, 119, 255, 254, 63, 255, 255, 33, 255, 255, 145, 255, 255, 201, 127, 255, 228, 255, 255, 242, 159, 255, 249, 95, 255, 252, 183, 255, 254, 95, 255, 255, 49, 255, 255, 153, 255, 255,205, 127, 255, 230, 255, 255, 243, 159, 255, 249, 223, 255, 252, 247, 255, 254,127, 255, 255, 65, 255, 255, 161, 255, 255, 209, 127, 255, 232, 255, 255, 244, 159, 255, 250, 95, 255, 253, 55, 255, 254, 159, 255, 255, 81, 255, 255, 169, 255, 255, 213, 127, 255, 234, 255, 255, 245, 159, 255, 250, 223, 255, 253, 119, 255, 254, 191, 255, 255, 97, 255, 255, 177, 255, 255, 217, 127, 255, 236, 255, 255,246, 159, 255, 251, 95, 255, 253, 183, 255, 254, 223, 255, 255, 113, 255, 255, 185, 255, 255, 221, 127, 255, 238, 255, 255, 247, 159, 255, 251, 223, 255, 253, 247, 255, 254, 255, 255, 255, 129, 255, 255, 193, 255, 255, 225, 127, 255, 240, 255, 255, 248, 159, 255, 252, 95, 255, 254, 55, 255, 255, 31, 255, 255, 145, 255, 255, 201, 255, 255, 229, 127, 255, 242, 255, 255, 249, 159, 255, 252, 223, 255, 254, 119, 255, 255, 63, 255, 255, 161, 255, 255, 209, 255, 255, 233, 127, 255,244, 255, 255, 250, 159, 255, 253, 95, 255, 254, 183, 255, 255, 95, 255, 255, 177, 255, 255, 217, 255, 255, 237, 127, 255, 246, 255];
-    let encoded_bytes: ~[u8] = encoder.encode(ascii_bytes);
+    let huffman_codes = ~[255, 199, 255, 253, 143, 255, 255, 226, 255, 255, 254, 63, 255, 255, 228, 255, 255, 254, 95, 255, 255, 230, 255, 255, 254, 127, 255, 255, 232, 255, 255, 234, 255, 255, 255, 243, 255, 255, 250, 127, 255, 255, 171, 255, 255, 255, 223, 255, 255, 235, 255, 255, 254, 207, 255, 255, 237, 255, 255, 254, 239, 255, 255, 239, 255, 255, 255, 15, 255, 255, 241, 255, 255, 255, 47, 255, 255, 255, 191, 255, 255, 207, 255, 255, 253, 63, 255, 255, 215, 255, 255, 253, 191, 255, 255, 223, 255, 255, 254, 63, 255, 255, 231, 255, 255, 254, 191, 255, 255, 237, 79, 227, 249, 255, 175, 252, 171, 241, 254, 191, 175, 239, 231, 253, 253, 44, 187, 0, 8, 153, 105, 183, 29, 121, 251, 159, 127, 255, 32, 255, 191, 243, 255, 80, 221, 189, 127, 6, 28, 88, 242, 101, 205, 159, 70, 157, 90, 246, 109, 221, 191, 135, 30, 95, 156, 255, 127, 247, 255, 252, 63, 249, 255, 228, 95, 255, 71, 25, 36, 44, 179, 78, 110, 157, 104, 166, 163, 215, 218, 196, 38, 222, 254, 60, 250, 247, 255, 251, 254, 127, 251, 255, 223, 255, 255, 252, 255, 254, 111, 255, 244, 191, 255, 159, 255, 250, 63, 255, 211, 255, 255, 83, 255, 253, 95, 255, 251, 63, 255, 235, 127, 255, 218, 255, 255, 183, 255, 255, 115, 255, 254, 239, 255, 253, 239, 255, 254, 191, 255, 251, 255, 255, 253, 159, 255, 253, 191, 255, 235, 255, 255, 224, 255, 255, 238, 255, 255, 195, 255, 255, 139, 255, 255, 31, 255, 254, 79, 255, 238, 127, 255, 177, 255, 255, 151, 255, 253, 159, 255, 252, 223, 255, 249, 255, 255, 251, 255, 255, 218, 255, 254, 239, 255, 244, 255, 255, 183, 255, 254, 231, 255, 254, 143, 255, 253, 63, 255, 222, 255, 255, 213, 255, 254, 239, 255, 251, 223, 255, 254, 31, 255, 223, 255, 255, 127, 255, 255, 95, 255, 254, 207, 255, 240, 127, 255, 135, 255, 254, 15, 255, 241, 127, 255, 237, 255, 255, 135, 255, 255, 119, 255, 254, 255, 255, 234, 255, 255, 139, 255, 254, 63, 255, 249, 63, 255, 248, 127, 255, 203, 255, 255, 55, 255, 255, 31, 255, 255, 131, 255, 255, 225, 255, 254, 191, 255, 227, 255, 255, 63, 255, 255, 47, 255, 250, 63, 255, 253, 159, 255, 255, 23, 255, 255, 199, 255, 255, 242, 127, 255, 253, 239, 255, 255, 191, 255, 255, 242, 255, 255, 248, 255, 255, 251, 127, 255, 151, 255, 248, 255, 255, 254, 111, 255, 255, 193, 255, 255, 248, 127, 255, 254, 127, 255, 255, 197, 255, 255, 229, 255, 254, 79, 255, 242, 255, 255, 253, 31, 255, 255, 79, 255, 255, 254, 255, 255, 254, 63, 255, 255, 201, 255, 255, 249, 127, 255, 179, 255, 255, 207, 255, 251, 127, 255, 205, 255, 255, 79, 255, 249, 255, 255, 209, 255, 255, 207, 255, 254, 175, 255, 250, 255, 255, 253, 223, 255, 254, 255, 255, 255, 79, 255, 255, 95, 255, 255, 171, 255, 255, 167, 255, 255, 215, 255, 255, 249, 191, 255, 254, 207, 255, 255, 183, 255, 255, 243, 255, 255, 254, 143, 255, 255, 211, 255, 255, 250, 191, 255, 255, 95, 255, 255, 255, 127, 255, 254, 207, 255, 255, 219, 255, 255, 251, 191, 255, 255, 127, 255, 255, 240, 255, 255, 251, 191];
+    let encoded_bytes: ~[u8] = encoder.encode(ascii_bytes.as_slice());
 
     assert!(encoded_bytes == huffman_codes);
+    assert!(encoded_len(ascii_bytes.as_slice()) == huffman_codes.len());
+}
+
+#[test]
+fn encoded_len_test() {
+    let encoder = HuffmanEncoder::new();
+
+    // RFC 7541, Appendix C.4.1
+    let www = (~"www.example.com").into_bytes();
+    assert!(encoder.encode(www.as_slice()) == ~[0xf1, 0xe3, 0xc2, 0xe5, 0xf2, 0x3a, 0x6b, 0xa0, 0xab, 0x90, 0xf4, 0xff]);
+    assert!(encoded_len(www.as_slice()) == 12);
+
+    // RFC 7541, Appendix C.4.2
+    let no_cache = (~"no-cache").into_bytes();
+    assert!(encoder.encode(no_cache.as_slice()) == ~[0xa8, 0xeb, 0x10, 0x64, 0x9c, 0xbf]);
+    assert!(encoded_len(no_cache.as_slice()) == 6);
+
+    assert!(encoder.encode(&[]) == ~[]);
+    assert!(encoded_len(&[]) == 0);
 }
\ No newline at end of file
@@ -0,0 +1,182 @@
+// Comments enclosed in quotes are citations from RFC 7541:
+// http://tools.ietf.org/html/rfc7541
+use serialize::json;
+use serialize::json::Json;
+
+use header_field::HeaderField;
+
+/// How a header field is represented in a header block.
+#[deriving(Eq, Show, Clone)]
+pub enum Indexing {
+    /// Refer to an entry of the static or the header table (Indexed Header Field).
+    /// Only possible if both the name and the value are found, otherwise `Incremental` is used,
+    /// so that the next occurrence of the header field can be indexed.
+    Indexed,
+    /// A literal which is added to the header table (Literal Header Field with Incremental Indexing).
+    Incremental,
+    /// A literal which is not added to the header table (Literal Header Field without Indexing).
+    WithoutIndexing,
+    /// A literal which must never be added to a header table, not even by an intermediary
+    /// re-encoding it (Literal Header Field Never Indexed).
+    NeverIndexed,
+}
+
+/// What the encoder found in the static table and the header table for a header field.
+#[deriving(Eq, Show, Clone)]
+pub enum Lookup {
+    /// Neither the name nor the value were found.
+    NoMatch,
+    /// The name was found, but not with the same value.
+    NameMatch,
+    /// Both the name and the value were found.
+    FullMatch,
+}
+
+/// Decides how the encoder represents each header field.
+/// Sensitive header fields are always encoded as `NeverIndexed`, without consulting the policy.
+pub trait IndexingPolicy {
+    /// Choose the representation of a header field.
+    fn indexing(&mut self, field: &HeaderField, lookup: Lookup) -> Indexing;
+
+    /// Whether to Huffman encode a string literal, given its length once Huffman encoded.
+    /// By default Huffman encoding is used if it is shorter.
+    fn use_huffman(&mut self, string: &[u8], encoded_len: uint) -> bool {
+        encoded_len < string.len()
+    }
+
+    /// The name and the state of the policy, from which `restore_policy` restores it
+    /// when an encoder is restored from a snapshot. By default None, and the policy
+    /// must be passed to `Encoder::restore_with_policy`.
+    fn snapshot(&self) -> Option<(~str, Json)> {
+        None
+    }
+}
+
+/// Restore a built-in policy from its name and state, see `IndexingPolicy::snapshot`.
+/// Returns None for any other policy.
+pub fn restore_policy(name: &str, state: &Json) -> Option<~IndexingPolicy> {
+    match (name, state) {
+        ("default", _)                                 => Some(~DefaultPolicy as ~IndexingPolicy),
+        ("aggressive", _)                              => Some(~AggressivePolicy as ~IndexingPolicy),
+        ("value-length", &json::Number(n)) if n >= 0.0 => Some(~ValueLengthPolicy::new(n as uint) as ~IndexingPolicy),
+        ("static-table", _)                            => Some(~StaticTablePolicy as ~IndexingPolicy),
+        ("deterministic", &json::Boolean(huffman))     => Some(~DeterministicPolicy::new(huffman) as ~IndexingPolicy),
+        _                                              => None,
+    }
+}
+
+/// The policy of a new encoder.
+/// Header fields are indexed if found, literals with an indexed name are not added
+/// to the header table, and header fields with new names are added to the header table.
+pub struct DefaultPolicy;
+
+impl IndexingPolicy for DefaultPolicy {
+    fn indexing(&mut self, _: &HeaderField, lookup: Lookup) -> Indexing {
+        match lookup {
+            FullMatch => Indexed,
+            // The chance of the next header block using the same value is assumed to be small
+            NameMatch => WithoutIndexing,
+            NoMatch   => Incremental,
+        }
+    }
+
+    fn snapshot(&self) -> Option<(~str, Json)> {
+        Some((~"default", json::Null))
+    }
+}
+
+/// Add every header field not found to the header table.
+/// Suits connections repeating the same header fields, at the cost of evicting more often.
+pub struct AggressivePolicy;
+
+impl IndexingPolicy for AggressivePolicy {
+    fn indexing(&mut self, _: &HeaderField, lookup: Lookup) -> Indexing {
+        match lookup {
+            FullMatch => Indexed,
+            _         => Incremental,
+        }
+    }
+
+    fn snapshot(&self) -> Option<(~str, Json)> {
+        Some((~"aggressive", json::Null))
+    }
+}
+
+/// Like `AggressivePolicy`, but header fields with values longer than
+/// `max_value_len` octets are not added to the header table,
+/// so they do not evict many header fields that are more likely to be reused.
+pub struct ValueLengthPolicy {
+    max_value_len: uint
+}
+
+impl ValueLengthPolicy {
+    pub fn new(max_value_len: uint) -> ValueLengthPolicy {
+        ValueLengthPolicy {
+            max_value_len: max_value_len
+        }
+    }
+}
+
+impl IndexingPolicy for ValueLengthPolicy {
+    fn indexing(&mut self, field: &HeaderField, lookup: Lookup) -> Indexing {
+        match lookup {
+            FullMatch                                   => Indexed,
+            _ if field.value.len() > self.max_value_len => WithoutIndexing,
+            _                                           => Incremental,
+        }
+    }
+
+    fn snapshot(&self) -> Option<(~str, Json)> {
+        Some((~"value-length", json::Number(self.max_value_len as f64)))
+    }
+}
+
+/// Never add anything to the header table, and only refer to the static table.
+/// The encoder then needs no memory for a header table, and neither does the peer's decoder.
+pub struct StaticTablePolicy;
+
+impl IndexingPolicy for StaticTablePolicy {
+    fn indexing(&mut self, _: &HeaderField, lookup: Lookup) -> Indexing {
+        match lookup {
+            FullMatch => Indexed,
+            _         => WithoutIndexing,
+        }
+    }
+
+    fn snapshot(&self) -> Option<(~str, Json)> {
+        Some((~"static-table", json::Null))
+    }
+}
+
+/// Add every header field not found to the header table, and either always or never
+/// use Huffman encoding. Together with `Encoder::encode_list` this makes the same
+/// choices as the examples of RFC 7541 Appendix C, which use Huffman encoding
+/// even where it is not shorter.
+pub struct DeterministicPolicy {
+    huffman: bool
+}
+
+impl DeterministicPolicy {
+    pub fn new(huffman: bool) -> DeterministicPolicy {
+        DeterministicPolicy {
+            huffman: huffman
+        }
+    }
+}
+
+impl IndexingPolicy for DeterministicPolicy {
+    fn indexing(&mut self, _: &HeaderField, lookup: Lookup) -> Indexing {
+        match lookup {
+            FullMatch => Indexed,
+            _         => Incremental,
+        }
+    }
+
+    fn use_huffman(&mut self, _: &[u8], _: uint) -> bool {
+        self.huffman
+    }
+
+    fn snapshot(&self) -> Option<(~str, Json)> {
+        Some((~"deterministic", json::Boolean(self.huffman)))
+    }
+}
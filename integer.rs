@@ -0,0 +1,338 @@
+/* 
+ * This module handles integer representations according to the HPACK specifications (RFC 7541).
+ * http://tools.ietf.org/html/rfc7541#section-5.1
+ *
+ * Integers are decoded into an explicit width (u32, u64 or uint). An integer
+ * which does not fit, or which uses more continuation octets than the width can
+ * need, is an error. Decoding can be suspended when the buffer runs out in the
+ * middle of an integer, and resumed when the next buffer arrives.
+ */
+use std::uint;
+use std::u32;
+use std::u64;
+
+/*
+ * if I < 2^N - 1, encode_int I on N bits
+ * else
+ *     encode_int (2^N - 1) on N bits
+ *     I = I - (2^N - 1)
+ *     while I >= 128
+ *         encode_int (I % 128 + 128) on 8 bits
+ *         I = I / 128
+ *     encode_int I on 8 bits
+ *
+ * N must be in the range [1; 8]. The bits of the first octet outside
+ * the prefix are 0, so the caller can set them afterwards.
+ */
+pub fn encode_u64(i: u64, n: u8) -> ~[u8] {
+    assert!(n >= 1 && n <= 8);
+
+    let mut buffer: ~[u8] = ~[];
+    let mut _i: u64 = i;
+    let bound: u64 = (1 << n) - 1; // (2^N - 1), at most 255
+
+    if i < bound {
+        buffer.push(i as u8);
+    } else {
+        buffer.push(bound as u8);
+
+        _i = _i - bound;
+
+        while _i >= 128 {
+            buffer.push((_i % 128 + 128) as u8);
+            _i = _i / 128;
+        }
+
+        buffer.push(_i as u8); // _i < 128
+    }
+
+    buffer
+}
+
+pub fn encode_u32(i: u32, n: u8) -> ~[u8] {
+    encode_u64(i as u64, n)
+}
+
+pub fn encode_int(i: uint, n: u8) -> ~[u8] {
+    encode_u64(i as u64, n)
+}
+
+#[deriving(Eq, Show, Clone)]
+pub enum IntegerError {
+    Truncated, // The buffer ended before the last octet of the integer
+    Overflow,  // The integer does not fit into its width, or has too many continuation octets
+}
+
+/*
+ * Decodes an integer one octet at a time, so decoding can be suspended
+ * when the buffer runs out in the middle of an integer and resumed
+ * when the next buffer arrives.
+ *
+ * if I < 2^N - 1, return I
+ * else
+ *     M = 0
+ *     repeat
+ *         B = next octet
+ *         I = I + (B & 127) * 2^M
+ *         M = M + 7
+ *     while B & 128 == 128
+ *     return I
+ */
+pub struct IntegerDecoder {
+    priv n: u8,
+    priv i: u64,
+    priv m: uint,
+    priv started: bool,
+    priv max: u64,         // The largest integer of the width
+    priv octets: uint,     // Continuation octets so far
+    priv max_octets: uint, // The most continuation octets an integer of the width can need
+}
+
+impl IntegerDecoder {
+    // Decode an integer that fits into an uint
+    // N must be in the range [1; 8]
+    pub fn new(n: u8) -> IntegerDecoder {
+        IntegerDecoder::with_width(n, uint::BITS, uint::MAX as u64)
+    }
+
+    // Decode an integer that fits into an u32
+    pub fn new_u32(n: u8) -> IntegerDecoder {
+        IntegerDecoder::with_width(n, u32::BITS, u32::MAX as u64)
+    }
+
+    // Decode an integer that fits into an u64
+    pub fn new_u64(n: u8) -> IntegerDecoder {
+        IntegerDecoder::with_width(n, u64::BITS, u64::MAX)
+    }
+
+    fn with_width(n: u8, bits: uint, max: u64) -> IntegerDecoder {
+        assert!(n >= 1 && n <= 8);
+
+        IntegerDecoder {
+            n: n,
+            i: 0,
+            m: 0,
+            started: false,
+            max: max,
+            octets: 0,
+            // Each continuation octet carries 7 bits.
+            // One more is allowed for the 2^N - 1 already in the prefix.
+            max_octets: (bits + 6) / 7 + 1,
+        }
+    }
+
+    // Feed the next octet of the integer.
+    // Returns Some(I) if the octet was the last one, or None if more octets are needed.
+    // The first octet is masked to its N prefix bits.
+    pub fn push(&mut self, octet: u8) -> Result<Option<u64>, IntegerError> {
+        if !self.started {
+            self.started = true;
+
+            let bound: u64 = (1 << self.n) - 1; // (2^N - 1)
+            self.i = octet as u64 & bound; // Mask N bits
+
+            if self.i < bound {
+                return Ok(Some(self.i));
+            }
+
+            return Ok(None);
+        }
+
+        // "Excessively large integer encodings - in value or octet length - MUST be treated as a decoding error."
+        self.octets += 1;
+        if self.octets > self.max_octets {
+            return Err(Overflow);
+        }
+
+        let b: u64 = (octet & 127) as u64;
+
+        if b != 0 {
+            // I + B * 2^M must not exceed the largest integer of the width
+            if self.m >= 64 || b > (self.max >> self.m) || self.i > self.max - (b << self.m) {
+                return Err(Overflow);
+            }
+
+            self.i = self.i + (b << self.m);
+        }
+        self.m = self.m + 7;
+
+        if octet & 128 != 128 {
+            Ok(Some(self.i))
+        } else {
+            Ok(None)
+        }
+    }
+
+    // Feed the octets of a buffer until the integer is complete.
+    // Returns Some((I, consumed octets)) if the integer ended inside the buffer,
+    // or None if all of the buffer was consumed and more octets are needed.
+    pub fn push_slice(&mut self, buffer: &[u8]) -> Result<Option<(u64, uint)>, IntegerError> {
+        for (i, octet) in buffer.iter().enumerate() {
+            match self.push(*octet) {
+                Ok(Some(value)) => return Ok(Some((value, i + 1))),
+                Ok(None)        => {},
+                Err(e)          => return Err(e),
+            }
+        }
+
+        Ok(None)
+    }
+}
+
+/*
+ * Decodes an integer from the beginning of a buffer without copying it.
+ * Returns a tuple of the integer and the number of octets it occupied.
+ */
+fn decode(mut decoder: IntegerDecoder, buffer: &[u8]) -> Result<(u64, uint), IntegerError> {
+    match decoder.push_slice(buffer) {
+        Ok(Some(result)) => Ok(result),
+        // The buffer is "prematurely" empty
+        Ok(None)         => Err(Truncated),
+        Err(e)           => Err(e),
+    }
+}
+
+pub fn decode_u64(buffer: &[u8], n: u8) -> Result<(u64, uint), IntegerError> {
+    decode(IntegerDecoder::new_u64(n), buffer)
+}
+
+pub fn decode_u32(buffer: &[u8], n: u8) -> Result<(u32, uint), IntegerError> {
+    match decode(IntegerDecoder::new_u32(n), buffer) {
+        Ok((value, length)) => Ok((value as u32, length)),
+        Err(e)              => Err(e),
+    }
+}
+
+pub fn decode_int(buffer: &[u8], n: u8) -> Result<(uint, uint), IntegerError> {
+    match decode(IntegerDecoder::new(n), buffer) {
+        Ok((value, length)) => Ok((value as uint, length)),
+        Err(e)              => Err(e),
+    }
+}
+
+#[test]
+fn encode_int_test() {
+    let t0 = encode_int(10, 5);
+    assert!(t0[0] == 10);
+
+    let t1 = encode_int(1337, 5);
+    assert!(t1[0] == 31);
+    assert!(t1[1] == 154);
+    assert!(t1[2] == 10);
+
+    let t2 = encode_int(42, 8);
+    assert!(t2[0] == 42);
+
+    // The largest integers of each width
+    let t3 = encode_u64(u64::MAX, 1);
+    assert!(t3.len() == 11 && t3[10] == 1);
+
+    let t4 = encode_u32(u32::MAX, 8);
+    assert!(t4 == ~[255, 128, 254, 255, 255, 15]);
+}
+
+#[test]
+fn decode_int_test() {
+    let b0 = ~[10];
+    let (t0, _) = decode_int(b0.as_slice(), 5).unwrap();
+    assert!(t0 == 10);
+
+    let b1 = ~[31, 154, 10];
+    let (t1, _) = decode_int(b1.as_slice(), 5).unwrap();
+    assert!(t1 == 1337);
+
+    let b2 = ~[42];
+    let (t2, _) = decode_int(b2.as_slice(), 8).unwrap();
+    assert!(t2 == 42);
+}
+
+#[test]
+fn encode_int_decode_int_test() {
+    let t0 = 123456789;
+    let p0 = 6;
+    let (_t0, _) = decode_int(encode_int(t0, p0).as_slice(), p0).unwrap();
+    assert!(_t0 == t0);
+
+    let t1 = 100;
+    let p1 = 1;
+    let (_t1, _) = decode_int(encode_int(t1, p1).as_slice(), p1).unwrap();
+    assert!(_t1 == t1);
+
+    let t2 = 22222222;
+    let p2 = 3;
+    let (_t2, _) = decode_int(encode_int(t2, p2).as_slice(), p2).unwrap();
+    assert!(_t2 == t2);
+
+    let t3 = 42;
+    let p3 = 8;
+    let (_t3, _) = decode_int(encode_int(t3, p3).as_slice(), p3).unwrap();
+    assert!(_t3 == t3);
+
+
+    // encode_int two numbers into the same buffer
+    // and test that we can extract them one by one
+    let t4 = 3999;
+    let t5 = 4000;
+    let b0 = encode_int(t4, 6);
+    let b1 = encode_int(t5, 7);
+
+    let mut b2 = ~[];
+    b2.push_all_move(b0);
+    b2.push_all_move(b1);
+
+    let (t6, consumed) = decode_int(b2.as_slice(), 6).unwrap();
+    let (t7, _) = decode_int(b2.slice_from(consumed), 7).unwrap();
+
+    assert!(t6 == t4);
+    assert!(t7 == t5);
+
+    // The largest integers of each width
+    for n in range(1u8, 9) {
+        assert!(decode_u64(encode_u64(u64::MAX, n).as_slice(), n) == Ok((u64::MAX, encode_u64(u64::MAX, n).len())));
+        assert!(decode_u32(encode_u32(u32::MAX, n).as_slice(), n) == Ok((u32::MAX, encode_u32(u32::MAX, n).len())));
+    }
+}
+
+#[test]
+fn decode_int_error_test() {
+    // The continuation flag is set on the last octet
+    let b0 = ~[31, 154, 138];
+    assert!(decode_int(b0.as_slice(), 5) == Err(Truncated));
+
+    // The prefix signals a continuation, but there is none
+    let b1 = ~[255];
+    assert!(decode_int(b1.as_slice(), 8) == Err(Truncated));
+
+    // An endless run of continuation octets
+    let mut b2 = ~[31];
+    for _ in range(0, 20) {
+        b2.push(255);
+    }
+    b2.push(1);
+    assert!(decode_int(b2.as_slice(), 5) == Err(Overflow));
+
+    // A run of 0xFF which is long enough to overflow, but not to exceed the cap
+    assert!(decode_u32(&[255, 255, 255, 255, 255, 15], 8) == Err(Overflow));
+    assert!(decode_u32(&[255, 128, 254, 255, 255, 16], 8) == Err(Overflow));
+
+    // Too many (zero) continuation octets, even though the value is small
+    assert!(decode_u32(&[255, 128, 128, 128, 128, 128, 128, 0], 8) == Err(Overflow));
+    assert!(decode_u32(&[255, 128, 128, 128, 128, 128, 0], 8) == Ok((255, 7)));
+}
+
+#[test]
+fn integer_decoder_test() {
+    // 1337 with a 5-bit prefix, fed one octet at a time
+    let mut d0 = IntegerDecoder::new(5);
+    assert!(d0.push(31 | 0xE0) == Ok(None)); // The bits outside the prefix are ignored
+    assert!(d0.push(154) == Ok(None));
+    assert!(d0.push(10) == Ok(Some(1337)));
+
+    let mut d1 = IntegerDecoder::new(7);
+    assert!(d1.push(0x80 | 42) == Ok(Some(42)));
+
+    // 1337 split between two buffers, followed by another octet
+    let mut d2 = IntegerDecoder::new_u32(5);
+    assert!(d2.push_slice(&[31, 154]) == Ok(None));
+    assert!(d2.push_slice(&[10, 42]) == Ok(Some((1337, 1))));
+}
@@ -1,4 +1,4 @@
-#![crate_id = "hpack#0.1-draft07"]
+#![crate_id = "hpack#0.1"]
 
 #![license = "MIT/ASL2"]
 
@@ -8,30 +8,40 @@
 #![feature(macro_rules)]
 
 extern crate collections;
+extern crate serialize;
 
 // Reexport items for beautiful API
 // (e.g. hpack::Decoder instead of hpack::decoder::Decoder)
-pub use self::decoder::Decoder;
-pub use self::encoder::Encoder;
-pub use self::header_field::HeaderField;
+pub use self::decoder::{Decoder, DecoderError, ErrorMode, RollBack, Poison};
+pub use self::encoder::{Encoder, EncoderError};
+pub use self::header_field::{HeaderField, HeaderFieldRef, MaybeOwnedBytes};
 pub use self::header_collection::HeaderCollection;
+pub use self::header_table::{HeaderTableView, TableEntries};
+pub use self::indexing_policy::IndexingPolicy;
+
+#[macro_escape]
+mod macros;
 
 mod encoder;
 mod decoder;
 mod header_field;
 mod header_collection;
 mod header_table;
-mod header_set;
-mod reference_set;
-mod integer_representation;
+pub mod integer;
 mod static_header_table;
-mod representation;
+pub mod representation;
 mod representation_encoder;
+pub mod indexing_policy;
+pub mod story;
+pub mod disassembler;
+pub mod snapshot;
+pub mod frame;
 mod huffman {
-    mod huffman_tree;
     mod huffman_codes;
+    mod huffman_decode_table;
     pub mod huffman_encoder;
     pub mod huffman_decoder;
 }
 
-mod test;
\ No newline at end of file
+mod test;
+mod rfc_test;
\ No newline at end of file
@@ -0,0 +1,21 @@
+// Macros shared by the modules of the crate. The module is declared first in lib.rs,
+// so the macros are defined before any other module is compiled.
+
+// Macro rule to unwrap a result
+// If Err, the function using this macro will return with the error
+// Invoke it like `propagate_err!(result)`
+// or `propagate_err!(option, error)` to turn a None into the given error
+macro_rules! propagate_err(
+    ($inp:expr) => (
+        match $inp {
+            Ok(x)  => x,
+            Err(e) => return Err(e),
+        }
+    );
+    ($inp:expr, $err:expr) => (
+        match $inp {
+            Some(x) => x,
+            None    => return Err($err),
+        }
+    );
+)
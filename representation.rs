@@ -1,3 +1,5 @@
+// The representations of RFC 7541 section 6. They are encoded by representation_encoder,
+// and parsed back by the disassembler.
 pub trait Representation {
     fn encode(&self) -> ~[u8];
 }
@@ -9,6 +11,7 @@ pub trait Representation {
  * +---+---------------------------+
  *        Indexed Header Field
  */
+#[deriving(Eq, Show, Clone)]
 pub struct IndexedHeader {
     index: uint,
 }
@@ -52,6 +55,7 @@ impl IndexedHeader {
  * +-------------------------------+
  * Literal Header Field never Indexed - Indexed Name
  */
+#[deriving(Eq, Show, Clone)]
 pub struct IndexedLiteral {
     indexing: bool,
     never_indexed: bool, 
@@ -117,6 +121,7 @@ impl IndexedLiteral {
  * +-------------------------------+
  * Literal Header Field never Indexed - New Name
  */
+#[deriving(Eq, Show, Clone)]
 pub struct NamedLiteral {
     indexing: bool,
     never_indexed: bool,
@@ -147,27 +152,19 @@ impl NamedLiteral {
 /*
  *   0   1   2   3   4   5   6   7
  * +---+---+---+---+---+---+---+---+
- * | 0 | 0 | 1 | 1 |       0       |
+ * | 0 | 0 | 1 |   Max size (5+)   |
  * +---+---------------------------+
- *       Reference Set Emptying 
- *
- *
- *   0   1   2   3   4   5   6   7
- * +---+---+---+---+---+---+---+---+
- * | 0 | 0 | 1 | 0 | Max size (4+) |
- * +---+---------------------------+
- * Maximum Header Table Size Change
+ * Dynamic Table Size Update
  */
+#[deriving(Eq, Show, Clone)]
 pub struct ContextUpdate {
-    flag: bool, // true: empty reference set, false: change header table size
-    data: uint
+    max_size: uint
 }
 
 impl ContextUpdate {
-    pub fn new(flag: bool, data: uint) -> ContextUpdate {
+    pub fn new(max_size: uint) -> ContextUpdate {
         ContextUpdate {
-            flag: flag,
-            data: data
+            max_size: max_size
         }
     }
-}
\ No newline at end of file
+}
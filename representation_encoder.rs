@@ -1,5 +1,5 @@
 use representation::{Representation, IndexedHeader, IndexedLiteral, NamedLiteral, ContextUpdate};
-use integer_representation::{encode_int};
+use integer::{encode_int};
 
 impl Representation for IndexedHeader {
     fn encode(&self) -> ~[u8] {
@@ -82,15 +82,8 @@ impl Representation for NamedLiteral {
 
 impl Representation for ContextUpdate {
     fn encode(&self) -> ~[u8] {
-        let mut buffer: ~[u8] = encode_int(self.data, 4);;
-        let mask: u8;
-
-        if self.flag {
-            mask = 0x30;
-        } else {
-            mask = 0x20;
-        }
-        buffer[0] = buffer[0] | mask; // We set the top bit
+        let mut buffer: ~[u8] = encode_int(self.max_size, 5);
+        buffer[0] = buffer[0] | 0x20; // 001X XXXX
 
         buffer
     }
@@ -162,4 +155,18 @@ fn named_literal_test() {
     assert!(h1.encode()[10] == 114); // The 3rd character is 'r'
     assert!(h1.encode()[11] == 108); // The 4th character is 'l'
     assert!(h1.encode()[12] == 100); // The 5th character is 'd'
-}
\ No newline at end of file
+}
+
+#[test]
+fn context_update_test() {
+    let h0 = ContextUpdate::new(0);
+    assert!(h0.encode() == ~[0x20]);
+
+    let h1 = ContextUpdate::new(30);
+    assert!(h1.encode() == ~[0x3E]);
+
+    let h2 = ContextUpdate::new(4096);
+    assert!(h2.encode()[0] == 0x3F); // 0010 0000 | 0001 1111
+    assert!(h2.encode()[1] == 225);
+    assert!(h2.encode()[2] == 31);
+}
@@ -0,0 +1,192 @@
+// The examples of RFC 7541 Appendix C:
+// http://tools.ietf.org/html/rfc7541#appendix-C
+//
+// Every header block is encoded and compared with the wire of the example,
+// which is then decoded. After each header block the header tables of both
+// the encoder and the decoder must hold the entries listed in the example.
+#[cfg(test)]
+mod rfc_test {
+    use serialize::hex::FromHex;
+
+    use header_field::HeaderField;
+    use test::fields;
+    use header_table::HeaderTableView;
+    use encoder::Encoder;
+    use decoder::Decoder;
+    use indexing_policy::{IndexingPolicy, DeterministicPolicy, Indexing, Lookup};
+    use indexing_policy::{Indexed, WithoutIndexing, FullMatch};
+
+    // C.2.2 sends a header field with an indexed name without indexing it
+    struct WithoutIndexingPolicy;
+
+    impl IndexingPolicy for WithoutIndexingPolicy {
+        fn indexing(&mut self, _: &HeaderField, lookup: Lookup) -> Indexing {
+            match lookup {
+                FullMatch => Indexed,
+                _         => WithoutIndexing,
+            }
+        }
+
+        fn use_huffman(&mut self, _: &[u8], _: uint) -> bool {
+            false
+        }
+    }
+
+    fn context(max_table_size: uint, policy: ~IndexingPolicy) -> (Encoder, Decoder) {
+        let mut encoder = Encoder::with_max_table_size(max_table_size);
+        encoder.set_indexing_policy(policy);
+
+        (encoder, Decoder::with_max_table_size(max_table_size))
+    }
+
+    // The entries of a header table from index 1 on, and its size
+    fn check_table(table: HeaderTableView, entries: &[(&str, &str)], size: uint) {
+        assert!(table.len() == entries.len());
+        assert!(table.size() == size);
+
+        for (field, &(name, value)) in table.iter().zip(entries.iter()) {
+            assert!(field.key.as_slice() == name.as_bytes());
+            assert!(field.value.as_slice() == value.as_bytes());
+        }
+    }
+
+    fn check_block(encoder: &mut Encoder, decoder: &mut Decoder, wire: &str, headers: ~[HeaderField],
+                   entries: &[(&str, &str)], size: uint) {
+        let wire = wire.from_hex().unwrap();
+
+        let header_block = encoder.encode_list(headers.as_slice()).unwrap();
+        assert!(header_block == wire);
+        check_table(encoder.header_table(), entries, size);
+
+        let decoded = decoder.decode_slice(wire.as_slice()).unwrap();
+        assert!(decoded.len() == headers.len());
+        for (field, header) in decoded.iter().zip(headers.iter()) {
+            assert!(field.to_header_field() == *header);
+        }
+        check_table(decoder.header_table(), entries, size);
+    }
+
+    #[test]
+    fn literal_with_indexing_test() {
+        // C.2.1
+        let (mut encoder, mut decoder) = context(4096, ~DeterministicPolicy::new(false) as ~IndexingPolicy);
+
+        check_block(&mut encoder, &mut decoder,
+                    "400a637573746f6d2d6b65790d637573746f6d2d686561646572",
+                    fields(&[("custom-key", "custom-header")]),
+                    &[("custom-key", "custom-header")], 55);
+    }
+
+    #[test]
+    fn literal_without_indexing_test() {
+        // C.2.2
+        let (mut encoder, mut decoder) = context(4096, ~WithoutIndexingPolicy as ~IndexingPolicy);
+
+        check_block(&mut encoder, &mut decoder,
+                    "040c2f73616d706c652f70617468",
+                    fields(&[(":path", "/sample/path")]),
+                    &[], 0);
+    }
+
+    #[test]
+    fn literal_never_indexed_test() {
+        // C.2.3
+        let (mut encoder, mut decoder) = context(4096, ~DeterministicPolicy::new(false) as ~IndexingPolicy);
+
+        check_block(&mut encoder, &mut decoder,
+                    "100870617373776f726406736563726574",
+                    ~[HeaderField::new_sensitive(~"password", ~"secret")],
+                    &[], 0);
+    }
+
+    #[test]
+    fn indexed_test() {
+        // C.2.4
+        let (mut encoder, mut decoder) = context(4096, ~DeterministicPolicy::new(false) as ~IndexingPolicy);
+
+        check_block(&mut encoder, &mut decoder, "82", fields(&[(":method", "GET")]), &[], 0);
+    }
+
+    fn check_requests(huffman: bool, wires: [&str, ..3]) {
+        let (mut encoder, mut decoder) = context(4096, ~DeterministicPolicy::new(huffman) as ~IndexingPolicy);
+
+        check_block(&mut encoder, &mut decoder, wires[0],
+                    fields(&[(":method", "GET"), (":scheme", "http"), (":path", "/"),
+                             (":authority", "www.example.com")]),
+                    &[(":authority", "www.example.com")], 57);
+
+        check_block(&mut encoder, &mut decoder, wires[1],
+                    fields(&[(":method", "GET"), (":scheme", "http"), (":path", "/"),
+                             (":authority", "www.example.com"), ("cache-control", "no-cache")]),
+                    &[("cache-control", "no-cache"), (":authority", "www.example.com")], 110);
+
+        check_block(&mut encoder, &mut decoder, wires[2],
+                    fields(&[(":method", "GET"), (":scheme", "https"), (":path", "/index.html"),
+                             (":authority", "www.example.com"), ("custom-key", "custom-value")]),
+                    &[("custom-key", "custom-value"), ("cache-control", "no-cache"),
+                      (":authority", "www.example.com")], 164);
+    }
+
+    #[test]
+    fn requests_without_huffman_test() {
+        // C.3
+        check_requests(false, ["828684410f7777772e6578616d706c652e636f6d",
+                               "828684be58086e6f2d6361636865",
+                               "828785bf400a637573746f6d2d6b65790c637573746f6d2d76616c7565"]);
+    }
+
+    #[test]
+    fn requests_with_huffman_test() {
+        // C.4
+        check_requests(true, ["828684418cf1e3c2e5f23a6ba0ab90f4ff",
+                              "828684be5886a8eb10649cbf",
+                              "828785bf408825a849e95ba97d7f8925a849e95bb8e8b4bf"]);
+    }
+
+    fn check_responses(huffman: bool, wires: [&str, ..3]) {
+        // The header table is limited to 256 octets, so header fields are evicted
+        let (mut encoder, mut decoder) = context(256, ~DeterministicPolicy::new(huffman) as ~IndexingPolicy);
+
+        check_block(&mut encoder, &mut decoder, wires[0],
+                    fields(&[(":status", "302"), ("cache-control", "private"),
+                             ("date", "Mon, 21 Oct 2013 20:13:21 GMT"),
+                             ("location", "https://www.example.com")]),
+                    &[("location", "https://www.example.com"),
+                      ("date", "Mon, 21 Oct 2013 20:13:21 GMT"),
+                      ("cache-control", "private"), (":status", "302")], 222);
+
+        check_block(&mut encoder, &mut decoder, wires[1],
+                    fields(&[(":status", "307"), ("cache-control", "private"),
+                             ("date", "Mon, 21 Oct 2013 20:13:21 GMT"),
+                             ("location", "https://www.example.com")]),
+                    &[(":status", "307"), ("location", "https://www.example.com"),
+                      ("date", "Mon, 21 Oct 2013 20:13:21 GMT"),
+                      ("cache-control", "private")], 222);
+
+        check_block(&mut encoder, &mut decoder, wires[2],
+                    fields(&[(":status", "200"), ("cache-control", "private"),
+                             ("date", "Mon, 21 Oct 2013 20:13:22 GMT"),
+                             ("location", "https://www.example.com"),
+                             ("content-encoding", "gzip"),
+                             ("set-cookie", "foo=ASDJKHQKBZXOQWEOPIUAXQWEOIU; max-age=3600; version=1")]),
+                    &[("set-cookie", "foo=ASDJKHQKBZXOQWEOPIUAXQWEOIU; max-age=3600; version=1"),
+                      ("content-encoding", "gzip"),
+                      ("date", "Mon, 21 Oct 2013 20:13:22 GMT")], 215);
+    }
+
+    #[test]
+    fn responses_without_huffman_test() {
+        // C.5
+        check_responses(false, ["4803333032580770726976617465611d4d6f6e2c203231204f637420323031332032303a31333a323120474d546e1768747470733a2f2f7777772e6578616d706c652e636f6d",
+                                "4803333037c1c0bf",
+                                "88c1611d4d6f6e2c203231204f637420323031332032303a31333a323220474d54c05a04677a69707738666f6f3d4153444a4b48514b425a584f5157454f50495541585157454f49553b206d61782d6167653d333630303b2076657273696f6e3d31"]);
+    }
+
+    #[test]
+    fn responses_with_huffman_test() {
+        // C.6, where "307" is Huffman encoded although it is not shorter
+        check_responses(true, ["488264025885aec3771a4b6196d07abe941054d444a8200595040b8166e082a62d1bff6e919d29ad171863c78f0b97c8e9ae82ae43d3",
+                               "4883640effc1c0bf",
+                               "88c16196d07abe941054d444a8200595040b8166e084a62d1bffc05a839bd9ab77ad94e7821dd7f2e6c7b335dfdfcd5b3960d5af27087f3672c1ab270fb5291f9587316065c003ed4ee5b1063d5007"]);
+    }
+}
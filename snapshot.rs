@@ -0,0 +1,419 @@
+// Snapshots of the state of an encoding or decoding context, e.g. to move a
+// connection to another process, or to attach the exact decoder state to a bug report.
+// `Encoder::snapshot` and `Decoder::snapshot` take them, and `Encoder::restore` and
+// `Decoder::restore` build a context which behaves identically.
+//
+// A snapshot is written as JSON. Names and values are hex encoded, since they
+// need not be UTF-8. The header table entries are listed from index 1 on.
+// {
+//   "version": 1,
+//   "context": "encoder",
+//   "max_table_size": 4096,
+//   "entries": [ ["3a617574686f72697479", "7777772e6578616d706c652e636f6d"], ... ],
+//   "max_header_list_size": 16384,                      (optional)
+//   "pending_size_update": [256, 1024],                 (encoder, optional)
+//   "sensitive_names": ["617574686f72697a6174696f6e"],   (encoder)
+//   "policy": { "name": "default", "state": null },     (encoder, optional)
+//   "protocol_max_table_size": 4096,                    (decoder)
+//   "size_update_required": false,                      (decoder)
+//   "error_mode": "roll-back"                           (decoder, optional, or "poison")
+// }
+use collections::TreeMap;
+use serialize::json;
+use serialize::json::Json;
+use serialize::hex::{ToHex, FromHex};
+
+use header_field::HeaderField;
+use decoder::{ErrorMode, RollBack, Poison};
+
+/// The version of the snapshot format written by `to_json`.
+/// Snapshots of other versions are rejected.
+pub static SNAPSHOT_VERSION: uint = 1;
+
+#[deriving(Eq, Show, Clone)]
+pub enum SnapshotError {
+    /// The text is not a snapshot (the reason).
+    InvalidSnapshot(~str),
+    /// The snapshot has another version (the value) than `SNAPSHOT_VERSION`.
+    UnsupportedVersion(uint),
+    /// The snapshot is of a decoder where an encoder was expected, or the other way around.
+    WrongContext,
+    /// The decoder is in the middle of a header block.
+    BlockInProgress,
+    /// The encoder or the decoder is poisoned, see `EncoderError` and `ErrorMode`.
+    ContextPoisoned,
+    /// The indexing policy (the name) cannot be restored by `indexing_policy::restore_policy`.
+    UnknownPolicy(~str),
+}
+
+/// The state of an `Encoder`.
+#[deriving(Eq, Show, Clone)]
+pub struct EncoderState {
+    max_table_size: uint,
+    /// The header table from index 1 on.
+    entries: ~[HeaderField],
+    max_header_list_size: Option<uint>,
+    /// The (smallest, final) header table size to signal in the next header block.
+    pending_size_update: Option<(uint, uint)>,
+    sensitive_names: ~[~[u8]],
+    /// The name and state of the indexing policy, see `IndexingPolicy::snapshot`.
+    policy: Option<(~str, Json)>,
+}
+
+/// The state of a `Decoder` between header blocks.
+#[deriving(Eq, Show, Clone)]
+pub struct DecoderState {
+    max_table_size: uint,
+    /// The header table from index 1 on.
+    entries: ~[HeaderField],
+    max_header_list_size: Option<uint>,
+    protocol_max_table_size: uint,
+    /// Whether the next header block must start with a Dynamic Table Size Update.
+    size_update_required: bool,
+    error_mode: ErrorMode,
+}
+
+impl EncoderState {
+    /// The snapshot as JSON text.
+    pub fn to_json(&self) -> ~str {
+        let mut object = new_object("encoder", self.max_table_size, self.entries.as_slice(), self.max_header_list_size);
+
+        match self.pending_size_update {
+            Some((smallest, last)) => {
+                object.insert(~"pending_size_update", json::List(~[json::Number(smallest as f64), json::Number(last as f64)]));
+            },
+            None => {}
+        }
+
+        object.insert(~"sensitive_names", json::List(self.sensitive_names.iter().map(|name| json::String(name.to_hex())).collect()));
+
+        match self.policy {
+            Some((ref name, ref state)) => {
+                let mut policy = ~TreeMap::new();
+                policy.insert(~"name", json::String(name.clone()));
+                policy.insert(~"state", state.clone());
+
+                object.insert(~"policy", json::Object(policy));
+            },
+            None => {}
+        }
+
+        json::Object(object).to_pretty_str()
+    }
+
+    /// Parse a snapshot of an encoder from its JSON text.
+    pub fn from_json(text: &str) -> Result<EncoderState, SnapshotError> {
+        let object = propagate_err!(parse_object(text, "encoder"));
+
+        let pending_size_update = match object.find(&~"pending_size_update") {
+            Some(&json::List(ref sizes)) if sizes.len() == 2 => {
+                Some((propagate_err!(to_uint(&sizes[0], "pending_size_update")),
+                      propagate_err!(to_uint(&sizes[1], "pending_size_update"))))
+            },
+            None => None,
+            _ => return Err(InvalidSnapshot(~"invalid pending_size_update")),
+        };
+
+        let mut sensitive_names = ~[];
+        match object.find(&~"sensitive_names") {
+            Some(&json::List(ref names)) => {
+                for name in names.iter() {
+                    sensitive_names.push(propagate_err!(to_octets(name, "sensitive_names")));
+                }
+            },
+            _ => return Err(InvalidSnapshot(~"invalid sensitive_names")),
+        }
+
+        let policy = match object.find(&~"policy") {
+            Some(&json::Object(ref policy)) => match (policy.find(&~"name"), policy.find(&~"state")) {
+                (Some(&json::String(ref name)), Some(state)) => Some((name.clone(), state.clone())),
+                _ => return Err(InvalidSnapshot(~"invalid policy")),
+            },
+            None => None,
+            _ => return Err(InvalidSnapshot(~"invalid policy")),
+        };
+
+        Ok(EncoderState {
+            max_table_size: propagate_err!(find_uint(&*object, "max_table_size")),
+            entries: propagate_err!(find_entries(&*object)),
+            max_header_list_size: propagate_err!(find_optional_uint(&*object, "max_header_list_size")),
+            pending_size_update: pending_size_update,
+            sensitive_names: sensitive_names,
+            policy: policy,
+        })
+    }
+}
+
+impl DecoderState {
+    /// The snapshot as JSON text.
+    pub fn to_json(&self) -> ~str {
+        let mut object = new_object("decoder", self.max_table_size, self.entries.as_slice(), self.max_header_list_size);
+
+        object.insert(~"protocol_max_table_size", json::Number(self.protocol_max_table_size as f64));
+        object.insert(~"size_update_required", json::Boolean(self.size_update_required));
+
+        let error_mode = match self.error_mode {
+            RollBack => "roll-back",
+            Poison   => "poison",
+        };
+        object.insert(~"error_mode", json::String(error_mode.to_owned()));
+
+        json::Object(object).to_pretty_str()
+    }
+
+    /// Parse a snapshot of a decoder from its JSON text.
+    pub fn from_json(text: &str) -> Result<DecoderState, SnapshotError> {
+        let object = propagate_err!(parse_object(text, "decoder"));
+
+        let size_update_required = match object.find(&~"size_update_required") {
+            Some(&json::Boolean(required)) => required,
+            _ => return Err(InvalidSnapshot(~"invalid size_update_required")),
+        };
+
+        let error_mode = match object.find(&~"error_mode") {
+            Some(&json::String(ref mode)) if mode.as_slice() == "roll-back" => RollBack,
+            Some(&json::String(ref mode)) if mode.as_slice() == "poison" => Poison,
+            None => RollBack,
+            _ => return Err(InvalidSnapshot(~"invalid error_mode")),
+        };
+
+        Ok(DecoderState {
+            max_table_size: propagate_err!(find_uint(&*object, "max_table_size")),
+            entries: propagate_err!(find_entries(&*object)),
+            max_header_list_size: propagate_err!(find_optional_uint(&*object, "max_header_list_size")),
+            protocol_max_table_size: propagate_err!(find_uint(&*object, "protocol_max_table_size")),
+            size_update_required: size_update_required,
+            error_mode: error_mode,
+        })
+    }
+}
+
+/// Check that the entries of a snapshot fit into its header table,
+/// so restoring them does not evict any of them.
+pub fn check_entries(entries: &[HeaderField], max_table_size: uint) -> Result<(), SnapshotError> {
+    let size = entries.iter().fold(0, |size, hf| size + hf.size());
+
+    if size > max_table_size {
+        return Err(InvalidSnapshot(~"the entries exceed max_table_size"));
+    }
+
+    Ok(())
+}
+
+// The members common to encoder and decoder snapshots
+fn new_object(context: &str, max_table_size: uint, entries: &[HeaderField], max_header_list_size: Option<uint>) -> ~TreeMap<~str, Json> {
+    let mut object = ~TreeMap::new();
+
+    object.insert(~"version", json::Number(SNAPSHOT_VERSION as f64));
+    object.insert(~"context", json::String(context.to_owned()));
+    object.insert(~"max_table_size", json::Number(max_table_size as f64));
+
+    let entries = entries.iter().map(|hf| {
+        json::List(~[json::String(hf.key.to_hex()), json::String(hf.value.to_hex())])
+    }).collect();
+    object.insert(~"entries", json::List(entries));
+
+    match max_header_list_size {
+        Some(size) => { object.insert(~"max_header_list_size", json::Number(size as f64)); },
+        None => {}
+    }
+
+    object
+}
+
+// Parse a snapshot and check its version and context
+fn parse_object(text: &str, context: &str) -> Result<~TreeMap<~str, Json>, SnapshotError> {
+    let object = match json::from_str(text) {
+        Ok(json::Object(object)) => object,
+        Ok(_)  => return Err(InvalidSnapshot(~"the snapshot is not an object")),
+        Err(e) => return Err(InvalidSnapshot(format!("{}", e))),
+    };
+
+    let version = propagate_err!(find_uint(&*object, "version"));
+    if version != SNAPSHOT_VERSION {
+        return Err(UnsupportedVersion(version));
+    }
+
+    match object.find(&~"context") {
+        Some(&json::String(ref name)) if name.as_slice() == context => Ok(object),
+        Some(&json::String(_)) => Err(WrongContext),
+        _ => Err(InvalidSnapshot(~"invalid context")),
+    }
+}
+
+fn to_uint(json: &Json, member: &str) -> Result<uint, SnapshotError> {
+    match *json {
+        json::Number(n) if n >= 0.0 && (n as uint) as f64 == n => Ok(n as uint),
+        _ => Err(InvalidSnapshot(format!("invalid {}", member))),
+    }
+}
+
+fn to_octets(json: &Json, member: &str) -> Result<~[u8], SnapshotError> {
+    match *json {
+        json::String(ref hex) => match hex.as_slice().from_hex() {
+            Ok(octets) => Ok(octets),
+            Err(_)     => Err(InvalidSnapshot(format!("invalid {}", member))),
+        },
+        _ => Err(InvalidSnapshot(format!("invalid {}", member))),
+    }
+}
+
+fn find_uint(object: &TreeMap<~str, Json>, member: &str) -> Result<uint, SnapshotError> {
+    match object.find(&member.to_owned()) {
+        Some(json) => to_uint(json, member),
+        None       => Err(InvalidSnapshot(format!("no {}", member))),
+    }
+}
+
+fn find_optional_uint(object: &TreeMap<~str, Json>, member: &str) -> Result<Option<uint>, SnapshotError> {
+    match object.find(&member.to_owned()) {
+        Some(json) => Ok(Some(propagate_err!(to_uint(json, member)))),
+        None       => Ok(None),
+    }
+}
+
+fn find_entries(object: &TreeMap<~str, Json>) -> Result<~[HeaderField], SnapshotError> {
+    let mut entries = ~[];
+
+    match object.find(&~"entries") {
+        Some(&json::List(ref list)) => {
+            for entry in list.iter() {
+                match *entry {
+                    json::List(ref pair) if pair.len() == 2 => {
+                        let name = propagate_err!(to_octets(&pair[0], "entries"));
+                        let value = propagate_err!(to_octets(&pair[1], "entries"));
+
+                        entries.push(HeaderField::from_bytes(name, value));
+                    },
+                    _ => return Err(InvalidSnapshot(~"invalid entries")),
+                }
+            }
+        },
+        _ => return Err(InvalidSnapshot(~"invalid entries")),
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod snapshot_test {
+    use encoder::Encoder;
+    use decoder::Decoder;
+    use header_field::HeaderField;
+    use test::fields;
+    use indexing_policy::{IndexingPolicy, ValueLengthPolicy, DefaultPolicy, Indexing, Lookup};
+    use snapshot::{EncoderState, DecoderState, SNAPSHOT_VERSION};
+    use snapshot::{InvalidSnapshot, UnsupportedVersion, WrongContext, BlockInProgress, UnknownPolicy};
+
+    #[test]
+    fn encoder_snapshot_test() {
+        let mut encoder = Encoder::new();
+        encoder.set_indexing_policy(~ValueLengthPolicy::new(20) as ~IndexingPolicy);
+        encoder.add_sensitive_name(~"authorization");
+        encoder.set_max_header_list_size(1000);
+
+        let hs0 = fields(&[(":authority", "www.example.com"), ("custom-key", "custom-value"),
+                           ("custom-key", "another-value")]);
+        encoder.encode_list(hs0.as_slice()).unwrap();
+        encoder.set_max_table_size(2048).unwrap();
+
+        let state = encoder.snapshot().unwrap();
+        let json = state.to_json();
+        assert!(EncoderState::from_json(json.as_slice()).unwrap() == state);
+
+        let mut restored = Encoder::restore(&EncoderState::from_json(json.as_slice()).unwrap()).unwrap();
+        assert!(restored.snapshot().unwrap().to_json() == json);
+
+        // Both encode the next header list the same, including the pending size update,
+        // the most recent custom-key and the sensitive name
+        let hs1 = fields(&[(":authority", "www.example.com"), ("custom-key", "third-value"),
+                           ("authorization", "secret"), ("a-long-header", "longer than twenty octets")]);
+        let block = encoder.encode_list(hs1.as_slice()).unwrap();
+        assert!(restored.encode_list(hs1.as_slice()).unwrap() == block);
+        assert!(restored.snapshot().unwrap() == encoder.snapshot().unwrap());
+    }
+
+    // A policy which cannot be restored from a snapshot
+    struct CustomPolicy;
+
+    impl IndexingPolicy for CustomPolicy {
+        fn indexing(&mut self, field: &HeaderField, lookup: Lookup) -> Indexing {
+            let mut policy = DefaultPolicy;
+            policy.indexing(field, lookup)
+        }
+    }
+
+    #[test]
+    fn custom_policy_snapshot_test() {
+        let mut encoder = Encoder::new();
+        encoder.set_indexing_policy(~CustomPolicy as ~IndexingPolicy);
+
+        let state = encoder.snapshot().unwrap();
+        assert!(state.policy.is_none());
+        match Encoder::restore(&state) {
+            Err(UnknownPolicy(_)) => {},
+            _ => fail!("A custom policy was restored"),
+        }
+        assert!(Encoder::restore_with_policy(&state, ~CustomPolicy as ~IndexingPolicy).is_ok());
+    }
+
+    #[test]
+    fn decoder_snapshot_test() {
+        let mut encoder = Encoder::new();
+        let mut decoder = Decoder::new();
+        decoder.set_max_header_list_size(1000);
+
+        let hs0 = fields(&[(":authority", "www.example.com"), ("custom-key", "custom-value")]);
+        let block = encoder.encode_list(hs0.as_slice()).unwrap();
+        decoder.decode_slice(block.as_slice()).unwrap();
+
+        // The peer must signal a smaller header table in the next header block
+        decoder.set_protocol_max_table_size(100);
+
+        let state = decoder.snapshot().unwrap();
+        let json = state.to_json();
+        let mut restored = Decoder::restore(&DecoderState::from_json(json.as_slice()).unwrap()).unwrap();
+        assert!(restored.snapshot().unwrap() == state);
+
+        // Both reject a header block without the size update, and decode one with it
+        assert!(decoder.decode_slice(&[0xbe]).is_err());
+        assert!(restored.decode_slice(&[0xbe]).is_err());
+
+        // 0x3f 0x45 = a Dynamic Table Size Update to 100 octets, which evicts :authority
+        let block: &[u8] = &[0x3f, 0x45, 0xbe];
+        let fields = decoder.decode_slice(block).unwrap();
+        let restored_fields = restored.decode_slice(block).unwrap();
+        assert!(fields[0].to_header_field() == restored_fields[0].to_header_field());
+        assert!(restored.snapshot().unwrap() == decoder.snapshot().unwrap());
+    }
+
+    #[test]
+    fn snapshot_error_test() {
+        let mut decoder = Decoder::new();
+
+        // Half of an indexed header field
+        decoder.decode_fragment(&[0xff]).unwrap();
+        assert!(decoder.snapshot() == Err(BlockInProgress));
+        assert!(decoder.decode_fragment(&[0x00]).is_err());
+        assert!(decoder.snapshot().is_ok());
+
+        let json = decoder.snapshot().unwrap().to_json();
+        assert!(EncoderState::from_json(json.as_slice()) == Err(WrongContext));
+
+        assert!(SNAPSHOT_VERSION != 2);
+        let json = "{\"version\": 2, \"context\": \"decoder\"}";
+        assert!(DecoderState::from_json(json) == Err(UnsupportedVersion(2)));
+
+        // A header table larger than our setting, without a pending size update
+        let mut state = Decoder::new().snapshot().unwrap();
+        state.protocol_max_table_size = 100;
+        match Decoder::restore(&state) {
+            Err(InvalidSnapshot(_)) => {},
+            _ => fail!("The oversized header table was restored"),
+        }
+
+        // With one, as after lowering the setting
+        state.size_update_required = true;
+        assert!(Decoder::restore(&state).is_ok());
+    }
+}
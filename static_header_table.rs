@@ -1,111 +1,160 @@
-// HPACK Static Table Draft 07:
-// http://tools.ietf.org/html/draft-ietf-httpbis-header-compression-07#appendix-B
+// HPACK Static Table RFC 7541:
+// http://tools.ietf.org/html/rfc7541#appendix-A
+
+use collections::HashMap;
 
 use header_field::HeaderField;
 
+static STATIC_HEADER_TABLE: &'static [(&'static str, &'static str)] = &'static [
+    /* 1*/ (":authority", ""),
+    /* 2*/ (":method", "GET"),
+    /* 3*/ (":method", "POST"),
+    /* 4*/ (":path", "/"),
+    /* 5*/ (":path", "/index.html"),
+    /* 6*/ (":scheme", "http"),
+    /* 7*/ (":scheme", "https"),
+    /* 8*/ (":status", "200"),
+    /* 9*/ (":status", "204"),
+    /*10*/ (":status", "206"),
+    /*11*/ (":status", "304"),
+    /*12*/ (":status", "400"),
+    /*13*/ (":status", "404"),
+    /*14*/ (":status", "500"),
+    /*15*/ ("accept-charset", ""),
+    /*16*/ ("accept-encoding", "gzip, deflate"),
+    /*17*/ ("accept-language", ""),
+    /*18*/ ("accept-ranges", ""),
+    /*19*/ ("accept", ""),
+    /*20*/ ("access-control-allow-origin", ""),
+    /*21*/ ("age", ""),
+    /*22*/ ("allow", ""),
+    /*23*/ ("authorization", ""),
+    /*24*/ ("cache-control", ""),
+    /*25*/ ("content-disposition", ""),
+    /*26*/ ("content-encoding", ""),
+    /*27*/ ("content-language", ""),
+    /*28*/ ("content-length", ""),
+    /*29*/ ("content-location", ""),
+    /*30*/ ("content-range", ""),
+    /*31*/ ("content-type", ""),
+    /*32*/ ("cookie", ""),
+    /*33*/ ("date", ""),
+    /*34*/ ("etag", ""),
+    /*35*/ ("expect", ""),
+    /*36*/ ("expires", ""),
+    /*37*/ ("from", ""),
+    /*38*/ ("host", ""),
+    /*39*/ ("if-match", ""),
+    /*40*/ ("if-modified-since", ""),
+    /*41*/ ("if-none-match", ""),
+    /*42*/ ("if-range", ""),
+    /*43*/ ("if-unmodified-since", ""),
+    /*44*/ ("last-modified", ""),
+    /*45*/ ("link", ""),
+    /*46*/ ("location", ""),
+    /*47*/ ("max-forwards", ""),
+    /*48*/ ("proxy-authenticate", ""),
+    /*49*/ ("proxy-authorization", ""),
+    /*50*/ ("range", ""),
+    /*51*/ ("referer", ""),
+    /*52*/ ("refresh", ""),
+    /*53*/ ("retry-after", ""),
+    /*54*/ ("server", ""),
+    /*55*/ ("set-cookie", ""),
+    /*56*/ ("strict-transport-security", ""),
+    /*57*/ ("transfer-encoding", ""),
+    /*58*/ ("user-agent", ""),
+    /*59*/ ("vary", ""),
+    /*60*/ ("via", ""),
+    /*61*/ ("www-authenticate", ""),
+];
+
 pub struct StaticHeaderTable {
-    fields: ~[HeaderField]
+    fields: &'static [(&'static str, &'static str)],
+    // Name -> (Index of the first header field with that name, Value -> Index)
+    index: HashMap<&'static [u8], (uint, HashMap<&'static [u8], uint>)>
 }
 
 impl StaticHeaderTable {
     pub fn new() -> StaticHeaderTable {
+        let mut index: HashMap<&'static [u8], (uint, HashMap<&'static [u8], uint>)> = HashMap::new();
+
+        for (i, &(name, value)) in STATIC_HEADER_TABLE.iter().enumerate() {
+            let entry = index.find_or_insert_with(name.as_bytes(), |_| (i + 1, HashMap::new()));
+            match *entry {
+                (_, ref mut values) => { values.insert(value.as_bytes(), i + 1); }
+            }
+        }
+
         StaticHeaderTable {
-            fields: ~[
-            /* 1*/ HeaderField::new(~":authority", ~""),
-            /* 2*/ HeaderField::new(~":method", ~"GET"),
-            /* 3*/ HeaderField::new(~":method", ~"POST"),
-            /* 4*/ HeaderField::new(~":path", ~"/"),
-            /* 5*/ HeaderField::new(~":path", ~"/index.html"),
-            /* 6*/ HeaderField::new(~":scheme", ~"http"),
-            /* 7*/ HeaderField::new(~":scheme", ~"https"),
-            /* 8*/ HeaderField::new(~":status", ~"200"),
-            /* 9*/ HeaderField::new(~":status", ~"204"),
-            /*10*/ HeaderField::new(~":status", ~"206"),
-            /*11*/ HeaderField::new(~":status", ~"304"),
-            /*12*/ HeaderField::new(~":status", ~"400"),
-            /*13*/ HeaderField::new(~":status", ~"404"),
-            /*14*/ HeaderField::new(~":status", ~"500"),
-            /*15*/ HeaderField::new(~"accept-charset", ~""),
-            /*16*/ HeaderField::new(~"accept-encoding", ~""),
-            /*17*/ HeaderField::new(~"accept-language", ~""),
-            /*18*/ HeaderField::new(~"accept-ranges", ~""),
-            /*19*/ HeaderField::new(~"accept", ~""),
-            /*20*/ HeaderField::new(~"access-control-allow-origin", ~""),
-            /*21*/ HeaderField::new(~"age", ~""),
-            /*22*/ HeaderField::new(~"allow", ~""),
-            /*23*/ HeaderField::new(~"authorization", ~""),
-            /*24*/ HeaderField::new(~"cache-control", ~""),
-            /*25*/ HeaderField::new(~"content-disposition", ~""),
-            /*26*/ HeaderField::new(~"content-encoding", ~""),
-            /*27*/ HeaderField::new(~"content-language", ~""),
-            /*28*/ HeaderField::new(~"content-length", ~""),
-            /*29*/ HeaderField::new(~"content-location", ~""),
-            /*30*/ HeaderField::new(~"content-range", ~""),
-            /*31*/ HeaderField::new(~"content-type", ~""),
-            /*32*/ HeaderField::new(~"cookie", ~""),
-            /*33*/ HeaderField::new(~"date", ~""),
-            /*34*/ HeaderField::new(~"etag", ~""),
-            /*35*/ HeaderField::new(~"expect", ~""),
-            /*36*/ HeaderField::new(~"expires", ~""),
-            /*37*/ HeaderField::new(~"from", ~""),
-            /*38*/ HeaderField::new(~"host", ~""),
-            /*39*/ HeaderField::new(~"if-match", ~""),
-            /*40*/ HeaderField::new(~"if-modified-since", ~""),
-            /*41*/ HeaderField::new(~"if-none-match", ~""),
-            /*42*/ HeaderField::new(~"if-range", ~""),
-            /*43*/ HeaderField::new(~"if-unmodified-since", ~""),
-            /*44*/ HeaderField::new(~"last-modified", ~""),
-            /*45*/ HeaderField::new(~"link", ~""),
-            /*46*/ HeaderField::new(~"location", ~""),
-            /*47*/ HeaderField::new(~"max-forwards", ~""),
-            /*48*/ HeaderField::new(~"proxy-authenticate", ~""),
-            /*49*/ HeaderField::new(~"proxy-authorization", ~""),
-            /*50*/ HeaderField::new(~"range", ~""),
-            /*51*/ HeaderField::new(~"referer", ~""),
-            /*52*/ HeaderField::new(~"refresh", ~""),
-            /*53*/ HeaderField::new(~"retry-after", ~""),
-            /*54*/ HeaderField::new(~"server", ~""),
-            /*55*/ HeaderField::new(~"set-cookie", ~""),
-            /*56*/ HeaderField::new(~"strict-transport-security", ~""),
-            /*57*/ HeaderField::new(~"transfer-encoding", ~""),
-            /*58*/ HeaderField::new(~"user-agent", ~""),
-            /*59*/ HeaderField::new(~"vary", ~""),
-            /*60*/ HeaderField::new(~"via", ~""),
-            /*61*/ HeaderField::new(~"www-authenticate", ~""),
-            ]
+            fields: STATIC_HEADER_TABLE,
+            index: index
         }
     }
 
+    // Number of header fields in the static header table
+    pub fn len(&self) -> uint {
+        self.fields.len()
+    }
+
     // Return the header field at 'index' - 1 from the static header table
     // Returns None if out of bounds
     // Remeber, HPACK uses 1-indexing!!!
     pub fn get(&self, index: uint) -> Option<HeaderField> {
+        match self.get_ref(index) {
+            Some((name, value)) => Some(HeaderField::new(name.to_owned(), value.to_owned())),
+            None => None
+        }
+    }
+
+    // Same as get, but without copying the name and value
+    pub fn get_ref(&self, index: uint) -> Option<(&'static str, &'static str)> {
         if index < 1 || index > self.fields.len() {
             return None;
         }
 
-        Some(self.fields[index - 1].clone())
+        Some(self.fields[index - 1])
     }
 
     // Search for a header field.
     // Return None if not found.
     // Return Some(index, full_match) where full_match is true
     // if both the name _and_ value match.
-    // If only a partial match is found it will return the last 
-    // partial match found.
-    pub fn find(&self, hf: HeaderField) -> Option<(uint, bool)> {
-        let mut partial_match = None; 
-
-        for i in range(0, self.fields.len()) {
-            if self.fields[i].key == hf.key {
-                if self.fields[i].value == hf.value {
-                    return Some((i + 1, true));
-                } else {
-                    partial_match = Some((i + 1, false));
+    // If only a partial match is found it will return the first
+    // header field with that name.
+    pub fn find(&self, hf: &HeaderField) -> Option<(uint, bool)> {
+        match self.index.find_equiv(&hf.key) {
+            Some(&(name_index, ref values)) => {
+                match values.find_equiv(&hf.value) {
+                    Some(&index) => Some((index, true)),
+                    None => Some((name_index, false))
                 }
-            }
+            },
+            None => None
         }
-
-        partial_match
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn static_header_table_test() {
+    let sht = StaticHeaderTable::new();
+
+    assert!(sht.len() == 61);
+
+    let h2 = sht.get(2).unwrap();
+    assert!(h2.key_str() == Some(":method") && h2.value_str() == Some("GET"));
+
+    let h16 = sht.get(16).unwrap();
+    assert!(h16.key_str() == Some("accept-encoding") && h16.value_str() == Some("gzip, deflate"));
+
+    let h61 = sht.get(61).unwrap();
+    assert!(h61.key_str() == Some("www-authenticate") && h61.value_str() == Some(""));
+
+    assert!(sht.get(0).is_none());
+    assert!(sht.get(62).is_none());
+
+    assert!(sht.find(&HeaderField::new(~":status", ~"304")) == Some((11, true)));
+    assert!(sht.find(&HeaderField::new(~":status", ~"418")) == Some((8, false)));
+    assert!(sht.find(&HeaderField::new(~"www-authenticate", ~"")) == Some((61, true)));
+    assert!(sht.find(&HeaderField::new(~"x-foo", ~"bar")).is_none());
+}
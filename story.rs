@@ -0,0 +1,346 @@
+// Reading, writing and running stories in the format of hpack-test-case:
+// https://github.com/http2jp/hpack-test-case
+//
+// A story is a sequence of header blocks (cases) which are encoded with the
+// same encoding context, and must be decoded with a single decoding context.
+// {
+//   "description": "...",
+//   "cases": [
+//     {
+//       "seqno": 0,
+//       "header_table_size": 4096,  (optional)
+//       "wire": "828684...",
+//       "headers": [ { ":method": "GET" }, ... ]
+//     },
+//     ...
+//   ]
+// }
+use std::str;
+use std::io::{File, IoError};
+use std::io::fs;
+use collections::{HashSet, TreeMap};
+use serialize::json;
+use serialize::json::Json;
+use serialize::hex::{ToHex, FromHex};
+
+use encoder::{Encoder, EncoderError};
+use decoder::{Decoder, DecoderError};
+use header_field::HeaderField;
+
+#[deriving(Show)]
+pub enum StoryError {
+    /// The story could not be read or written.
+    StoryIoError(IoError),
+    /// The story is not a story (the reason).
+    InvalidStory(~str),
+    /// The header list of a case (the seqno) could not be encoded.
+    CaseEncodeError(uint, EncoderError),
+    /// The wire of a case (the seqno) could not be decoded.
+    CaseDecodeError(uint, DecoderError),
+    /// The wire of a case (the seqno) decoded into other headers than the story says.
+    CaseMismatch(uint),
+}
+
+/// A single header block of a story.
+#[deriving(Clone)]
+pub struct Case {
+    seqno: uint,
+    /// The SETTINGS_HEADER_TABLE_SIZE in effect from this case on.
+    header_table_size: Option<uint>,
+    wire: ~[u8],
+    /// The header list in the order of the wire.
+    headers: ~[HeaderField],
+}
+
+#[deriving(Clone)]
+pub struct Story {
+    description: ~str,
+    cases: ~[Case],
+}
+
+impl Story {
+    /// Parse a story from its JSON text.
+    pub fn from_json(text: &str) -> Result<Story, StoryError> {
+        let json = match json::from_str(text) {
+            Ok(json) => json,
+            Err(e)   => return Err(InvalidStory(format!("{}", e))),
+        };
+
+        let story = match json {
+            json::Object(story) => story,
+            _ => return Err(InvalidStory(~"the story is not an object")),
+        };
+
+        let description = match story.find(&~"description") {
+            Some(&json::String(ref description)) => description.clone(),
+            _ => ~"",
+        };
+
+        let mut cases = ~[];
+
+        match story.find(&~"cases") {
+            Some(&json::List(ref list)) => {
+                for (i, case) in list.iter().enumerate() {
+                    cases.push(propagate_err!(parse_case(case, i)));
+                }
+            },
+            _ => return Err(InvalidStory(~"the story has no list of cases")),
+        }
+
+        Ok(Story {
+            description: description,
+            cases: cases,
+        })
+    }
+
+    /// Read a story from a file.
+    pub fn load(path: &Path) -> Result<Story, StoryError> {
+        match File::open(path).read_to_str() {
+            Ok(text) => Story::from_json(text.as_slice()),
+            Err(e)   => Err(StoryIoError(e)),
+        }
+    }
+
+    /// Encode a sequence of header lists with an encoder into a story.
+    /// The headers of each case are listed in the order the encoder wrote them,
+    /// which is found by decoding the wire. Returns `CaseDecodeError` if our own decoder
+    /// cannot decode what the encoder wrote.
+    pub fn encode(encoder: &mut Encoder, description: ~str, header_lists: ~[~HashSet<HeaderField>]) -> Result<Story, StoryError> {
+        let header_table_size = encoder.get_max_table_size();
+        let mut decoder = Decoder::with_max_table_size(header_table_size);
+        let mut cases = ~[];
+
+        for (seqno, header_list) in header_lists.move_iter().enumerate() {
+            let wire = match encoder.encode(header_list) {
+                Ok(wire) => wire,
+                Err(e)   => return Err(CaseEncodeError(seqno, e)),
+            };
+
+            let headers: ~[HeaderField] = match decoder.decode_slice(wire.as_slice()) {
+                Ok(fields) => fields.iter().map(|hf| hf.to_header_field()).collect(),
+                Err(e)     => return Err(CaseDecodeError(seqno, e)),
+            };
+
+            cases.push(Case {
+                seqno: seqno,
+                header_table_size: if seqno == 0 { Some(header_table_size) } else { None },
+                wire: wire,
+                headers: headers,
+            });
+        }
+
+        Ok(Story {
+            description: description,
+            cases: cases,
+        })
+    }
+
+    /// The story as JSON text.
+    /// Names and values which are not valid UTF-8 are written lossily.
+    pub fn to_json(&self) -> ~str {
+        let mut story = ~TreeMap::new();
+
+        story.insert(~"description", json::String(self.description.clone()));
+        story.insert(~"cases", json::List(self.cases.iter().map(|case| case_to_json(case)).collect()));
+
+        json::Object(story).to_pretty_str()
+    }
+
+    /// Write the story to a file.
+    pub fn save(&self, path: &Path) -> Result<(), StoryError> {
+        match File::create(path).write_str(self.to_json().as_slice()) {
+            Ok(()) => Ok(()),
+            Err(e) => Err(StoryIoError(e)),
+        }
+    }
+
+    /// Decode every case with a single decoder, and compare the header lists in order.
+    pub fn run(&self) -> Result<(), StoryError> {
+        let mut decoder = match self.cases.head() {
+            Some(&Case { header_table_size: Some(size), .. }) => Decoder::with_max_table_size(size),
+            _ => Decoder::new(),
+        };
+
+        for case in self.cases.iter() {
+            match case.header_table_size {
+                Some(size) => decoder.set_protocol_max_table_size(size),
+                None => {}
+            }
+
+            let fields = match decoder.decode_slice(case.wire.as_slice()) {
+                Ok(fields) => fields,
+                Err(e)     => return Err(CaseDecodeError(case.seqno, e)),
+            };
+
+            if fields.len() != case.headers.len() {
+                return Err(CaseMismatch(case.seqno));
+            }
+
+            for (field, header) in fields.iter().zip(case.headers.iter()) {
+                if field.key.as_slice() != header.key.as_slice() || field.value.as_slice() != header.value.as_slice() {
+                    return Err(CaseMismatch(case.seqno));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Run every story (*.json) in a directory.
+/// Returns the number of stories, or the first story that failed.
+pub fn run_stories(directory: &Path) -> Result<uint, (Path, StoryError)> {
+    let mut paths = match fs::readdir(directory) {
+        Ok(paths) => paths,
+        Err(e)    => return Err((directory.clone(), StoryIoError(e))),
+    };
+    paths.sort_by(|a, b| a.as_vec().cmp(&b.as_vec()));
+
+    let mut stories = 0;
+
+    for path in paths.iter().filter(|path| path.extension_str() == Some("json")) {
+        match Story::load(path).and_then(|story| story.run()) {
+            Ok(()) => stories += 1,
+            Err(e) => return Err((path.clone(), e)),
+        }
+    }
+
+    Ok(stories)
+}
+
+fn parse_case(json: &Json, index: uint) -> Result<Case, StoryError> {
+    let case = match *json {
+        json::Object(ref case) => case,
+        _ => return Err(InvalidStory(format!("case {} is not an object", index))),
+    };
+
+    let seqno = match case.find(&~"seqno") {
+        Some(&json::Number(seqno)) => seqno as uint,
+        _ => index,
+    };
+
+    let header_table_size = match case.find(&~"header_table_size") {
+        Some(&json::Number(size)) if size >= 0.0 => Some(size as uint),
+        None => None,
+        _ => return Err(InvalidStory(format!("case {}: invalid header_table_size", seqno))),
+    };
+
+    let wire = match case.find(&~"wire") {
+        Some(&json::String(ref wire)) => match wire.as_slice().from_hex() {
+            Ok(wire) => wire,
+            Err(_)   => return Err(InvalidStory(format!("case {}: the wire is not hex", seqno))),
+        },
+        _ => return Err(InvalidStory(format!("case {}: no wire", seqno))),
+    };
+
+    let mut headers = ~[];
+
+    match case.find(&~"headers") {
+        Some(&json::List(ref list)) => {
+            // Every header is an object with a single name
+            for header in list.iter() {
+                match *header {
+                    json::Object(ref header) if header.len() == 1 => {
+                        for (name, value) in header.iter() {
+                            match *value {
+                                json::String(ref value) => headers.push(HeaderField::new(name.clone(), value.clone())),
+                                _ => return Err(InvalidStory(format!("case {}: the value of {} is not a string", seqno, name))),
+                            }
+                        }
+                    },
+                    _ => return Err(InvalidStory(format!("case {}: a header is not a single name and value", seqno))),
+                }
+            }
+        },
+        _ => return Err(InvalidStory(format!("case {}: no headers", seqno))),
+    }
+
+    Ok(Case {
+        seqno: seqno,
+        header_table_size: header_table_size,
+        wire: wire,
+        headers: headers,
+    })
+}
+
+fn case_to_json(case: &Case) -> Json {
+    let mut object = ~TreeMap::new();
+
+    object.insert(~"seqno", json::Number(case.seqno as f64));
+    match case.header_table_size {
+        Some(size) => { object.insert(~"header_table_size", json::Number(size as f64)); },
+        None => {}
+    }
+    object.insert(~"wire", json::String(case.wire.as_slice().to_hex()));
+
+    let headers = case.headers.iter().map(|hf| {
+        let mut header = ~TreeMap::new();
+        header.insert(str::from_utf8_lossy(hf.key.as_slice()).into_owned(), json::String(str::from_utf8_lossy(hf.value.as_slice()).into_owned()));
+        json::Object(header)
+    }).collect();
+    object.insert(~"headers", json::List(headers));
+
+    json::Object(object)
+}
+
+#[cfg(test)]
+mod story_test {
+    use collections::HashSet;
+
+    use encoder::{Encoder, HeaderListTooLarge};
+    use header_field::HeaderField;
+    use story::{Story, CaseMismatch, CaseEncodeError, run_stories};
+
+    #[test]
+    fn fixtures_test() {
+        // The stories in fixtures/ next to the sources
+        let directory = Path::new(file!()).dir_path().join("fixtures");
+
+        match run_stories(&directory) {
+            Ok(stories) => assert!(stories > 0),
+            Err((path, e)) => fail!("{}: {}", path.display(), e),
+        }
+    }
+
+    #[test]
+    fn encode_story_test() {
+        let mut encoder = Encoder::with_max_table_size(256);
+
+        let mut hs0 = ~HashSet::new();
+        hs0.insert(HeaderField::new(~":status", ~"302"));
+        hs0.insert(HeaderField::new(~"location", ~"https://www.example.com"));
+
+        let mut hs1 = ~HashSet::new();
+        hs1.insert(HeaderField::new(~":status", ~"307"));
+        hs1.insert(HeaderField::new(~"location", ~"https://www.example.com"));
+
+        let story = Story::encode(&mut encoder, ~"encode_story_test", ~[hs0, hs1]).unwrap();
+        assert!(story.cases.len() == 2);
+        assert!(story.cases[0].header_table_size == Some(256));
+
+        // A written story reads back the same, and runs
+        let story = Story::from_json(story.to_json().as_slice()).unwrap();
+        assert!(story.description == ~"encode_story_test");
+        assert!(story.cases[0].header_table_size == Some(256));
+        assert!(story.cases[1].header_table_size.is_none());
+        assert!(story.cases[1].headers.len() == 2);
+        assert!(story.run().is_ok());
+
+        // Tampering with the headers is detected
+        let mut story = story;
+        story.cases[1].headers[0].value = (~"200").into_bytes();
+        match story.run() {
+            Err(CaseMismatch(1)) => {},
+            _ => fail!("The mismatch was not detected"),
+        }
+
+        // An encoder error is reported with the case it happened in
+        let mut hs2 = ~HashSet::new();
+        hs2.insert(HeaderField::new(~":status", ~"200"));
+        encoder.set_max_header_list_size(10);
+        match Story::encode(&mut encoder, ~"encode_story_test", ~[hs2]) {
+            Err(CaseEncodeError(0, HeaderListTooLarge(_))) => {},
+            _ => fail!("The encoder error was not reported"),
+        }
+    }
+}
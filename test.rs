@@ -1,7 +1,17 @@
+// Helpers shared by the tests of the crate
+#[cfg(test)]
+use header_field::HeaderField;
+
+// Header fields from (name, value) pairs
+#[cfg(test)]
+pub fn fields(headers: &[(&str, &str)]) -> ~[HeaderField] {
+    headers.iter().map(|&(name, value)| HeaderField::new(name.to_owned(), value.to_owned())).collect()
+}
+
 #[cfg(test)]
 mod test {
     use collections::hashmap::HashSet;
-    use integer_representation::encode_int; 
+    use integer::encode_int; 
 
     use header_field::HeaderField;
     use encoder::Encoder;
@@ -23,11 +33,11 @@ mod test {
         hb0.insert(h0.clone());
         hb0.insert(h1.clone());
 
-        let hs0_encoded = hpack_encoder.encode(hb0);
+        let hs0_encoded = hpack_encoder.encode(hb0).unwrap();
         let hs0_decoded = hpack_decoder.decode(hs0_encoded.clone()).unwrap();
 
-        assert!(hs0_decoded.get(&h0.key)[0] == h0.value);
-        assert!(hs0_decoded.get(&h1.key)[0] == h1.value);
+        assert!(hs0_decoded.get(h0.key.as_slice())[0] == h0.value);
+        assert!(hs0_decoded.get(h1.key.as_slice())[0] == h1.value);
 
 
         /*
@@ -42,12 +52,12 @@ mod test {
         hb1.insert(h3.clone());
         hb1.insert(h4.clone());
 
-        let hs1_encoded = hpack_encoder.encode(hb1);
+        let hs1_encoded = hpack_encoder.encode(hb1).unwrap();
         let hs1_decoded = hpack_decoder.decode(hs1_encoded.clone()).unwrap();
 
-        assert!(hs1_decoded.get(&h2.key)[0] == h2.value);
-        assert!(hs1_decoded.get(&h3.key)[0] == h3.value);
-        assert!(hs1_decoded.get(&h4.key)[0] == h4.value);
+        assert!(hs1_decoded.get(h2.key.as_slice())[0] == h2.value);
+        assert!(hs1_decoded.get(h3.key.as_slice())[0] == h3.value);
+        assert!(hs1_decoded.get(h4.key.as_slice())[0] == h4.value);
 
         /*
          * Test a mix of header fields. Some in header table, some in static header table etc.
@@ -79,21 +89,40 @@ mod test {
         hb2.insert(h15.clone());
         hb2.insert(h16.clone());
 
-        let hs2_encoded = hpack_encoder.encode(hb2);
+        let hs2_encoded = hpack_encoder.encode(hb2).unwrap();
         let hs2_decoded = hpack_decoder.decode(hs2_encoded.clone()).unwrap();
 
-        assert!(hs2_decoded.get(&h5.key)[0] == h5.value);
-        assert!(hs2_decoded.get(&h6.key)[0] == h6.value);
-        assert!(hs2_decoded.get(&h7.key)[0] == h7.value);
-        assert!(hs2_decoded.get(&h8.key)[0] == h8.value);
-        assert!(hs2_decoded.get(&h9.key)[0] == h9.value);
-        assert!(hs2_decoded.get(&h10.key)[0] == h10.value);
-        assert!(hs2_decoded.get(&h11.key).len() == 2);
-        assert!(hs2_decoded.get(&h12.key)[0] == h12.value);
-        assert!(hs2_decoded.get(&h13.key)[0] == h13.value);
-        assert!(hs2_decoded.get(&h14.key)[0] == h14.value);
-        assert!(hs2_decoded.get(&h15.key)[0] == h15.value);
-        assert!(hs2_decoded.get(&h16.key)[0] == h16.value);
+        assert!(hs2_decoded.get(h5.key.as_slice())[0] == h5.value);
+        assert!(hs2_decoded.get(h6.key.as_slice())[0] == h6.value);
+        assert!(hs2_decoded.get(h7.key.as_slice())[0] == h7.value);
+        assert!(hs2_decoded.get(h8.key.as_slice())[0] == h8.value);
+        assert!(hs2_decoded.get(h9.key.as_slice())[0] == h9.value);
+        assert!(hs2_decoded.get(h10.key.as_slice())[0] == h10.value);
+        assert!(hs2_decoded.get(h11.key.as_slice()).len() == 1);
+        assert!(hs2_decoded.get(h11.key.as_slice())[0] == h11.value);
+        assert!(hs2_decoded.get(h12.key.as_slice())[0] == h12.value);
+        assert!(hs2_decoded.get(h13.key.as_slice())[0] == h13.value);
+        assert!(hs2_decoded.get(h14.key.as_slice())[0] == h14.value);
+        assert!(hs2_decoded.get(h15.key.as_slice())[0] == h15.value);
+        assert!(hs2_decoded.get(h16.key.as_slice())[0] == h16.value);
+    }
+
+    #[test]
+    fn test_obs_text() {
+        let mut hpack_decoder = ~Decoder::new();
+        let mut hpack_encoder = ~Encoder::new();
+
+        // A Latin-1 filename is not valid UTF-8, but a valid field value
+        let mut hb0 = ~HashSet::new();
+        let mut value = (~"attachment; filename=\"f").into_bytes();
+        value.push_all(&[0xE6, 0x72, 0x22]); // ær" in Latin-1
+        let h0 = HeaderField::from_bytes((~"content-disposition").into_bytes(), value);
+        hb0.insert(h0.clone());
+
+        let hs0_encoded = hpack_encoder.encode(hb0).unwrap();
+        let hs0_decoded = hpack_decoder.decode(hs0_encoded.clone()).unwrap();
+
+        assert!(hs0_decoded.get(h0.key.as_slice())[0] == h0.value);
     }
 
     #[test]
@@ -114,8 +143,10 @@ mod test {
         let header_fields = hpack_decoder.decode(frame.clone()).unwrap();
         let header_fields2 = hpack_decoder.decode(~[]).unwrap();
 
-        assert!(header_fields.get(&h.key)[0] == value.clone());
-        assert!(header_fields2.get(&h.key)[0] == value);
+        // An empty header block yields an empty header list,
+        // even though the header field is still in the header table
+        assert!(header_fields.get(h.key.as_slice())[0].as_slice() == value.as_bytes());
+        assert!(header_fields2.get(h.key.as_slice()).len() == 0);
     }
 
     // #[test]